@@ -1,4 +1,5 @@
-use crate::common::{AttachedBlock, Block, BLOCK_CONFIG, MAP_CONFIG};
+use crate::common::{AttachedBlock, Block, CtxWrapper, PlayerGrid, BLOCK_CONFIG, MAP_CONFIG};
+use crate::module_bindings::{BlockTableAccess, OwnerType};
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use rand::random;
@@ -50,6 +51,63 @@ pub fn setup_block(
     //}
 }
 
+pub fn spawn_loose_blocks(
+    mut commands: Commands,
+    ctx_wrapper: Res<CtxWrapper>,
+    asset_server: Res<AssetServer>,
+    mut spawned_blocks: ResMut<SpawnedBlocks>,
+) {
+    let mut rng = rand::rng();
+
+    for block in ctx_wrapper.ctx.db.block().iter() {
+        if spawned_blocks.ids.contains(&block.id) || block.owner != OwnerType::None {
+            continue;
+        }
+
+        // Loose blocks, e.g. dropped by a destroyed obstacle, spawn where the server put them
+        let texture_index = rng.random_range(0..BLOCK_CONFIG.path.len());
+        let block_entity = commands.spawn((
+            Sprite {
+                custom_size: Some(BLOCK_CONFIG.size),
+                image: asset_server.load(BLOCK_CONFIG.path[texture_index]),
+                ..default()
+            },
+            Transform::from_xyz(block.position.x, block.position.y, 1.0),
+            Block {},
+        ));
+        spawned_blocks.ids.insert(block.id);
+        spawned_blocks.entities.insert(block_entity.id(), block.id);
+    }
+}
+
+/// Despawns blocks the server destroyed, e.g. burnt off by lava, and removes them from every grid.
+pub fn despawn_destroyed_blocks(
+    mut commands: Commands,
+    ctx_wrapper: Res<CtxWrapper>,
+    mut grid_query: Query<&mut PlayerGrid>,
+    mut spawned_blocks: ResMut<SpawnedBlocks>,
+) {
+    let destroyed: Vec<(Entity, u64)> = spawned_blocks
+        .entities
+        .iter()
+        .filter(|(_, block_id)| ctx_wrapper.ctx.db.block().id().find(block_id).is_none())
+        .map(|(entity, block_id)| (*entity, *block_id))
+        .collect();
+
+    for (entity, block_id) in destroyed {
+        for mut grid in grid_query.iter_mut() {
+            let before = grid.block_position.len();
+            grid.block_position.retain(|_, block_entity| *block_entity != entity);
+            if grid.block_position.len() < before {
+                grid.load -= 1;
+            }
+        }
+        commands.entity(entity).despawn();
+        spawned_blocks.entities.remove(&entity);
+        spawned_blocks.ids.remove(&block_id);
+    }
+}
+
 pub fn update_block(
     mut _commands: Commands,
     _window_query: Query<&Window, With<PrimaryWindow>>,
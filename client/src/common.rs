@@ -2,46 +2,95 @@
 // Configuration and shared components for the game
 //
 
-use crate::module_bindings::DbConnection;
+use crate::module_bindings::{DbConnection, ObstacleShape, SubscriptionHandle};
 use bevy::prelude::*;
 use bevy::text::{FontSmoothing, LineBreak, TextBounds};
 use bevy_ecs_tilemap::prelude::*;
+use rustbourn_terrain::Terrain;
 use spacetimedb_sdk::Identity;
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 #[derive(Component)]
 pub struct HookCooldown {
     pub timer: Timer,
 }
 
-#[derive(Resource, Debug)]
-pub struct LavaTiles {
-    pub positions: HashSet<(u32, u32)>,
+// Terrain of the map, decoded once when entering the game
+#[derive(Resource)]
+pub struct MapTerrain {
+    pub terrain: Terrain,
 }
+
 #[derive(Resource, Debug)]
 pub struct HookTimer(pub Timer);
 
+// Tilemap chunks currently spawned around the camera, keyed by chunk coordinate
 #[derive(Resource, Debug)]
-pub struct WaterTiles {
-    pub positions: HashSet<(u32, u32)>,
+pub struct TilemapChunks {
+    pub loaded: HashMap<IVec2, Entity>,
+    pub textures: Vec<Handle<Image>>,
 }
 
-#[derive(Resource, Debug)]
-pub struct RegTiles {
-    pub positions: HashSet<(u32, u32)>,
+// Server the client connects to when pressing "Play", picked in the server browser
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct ServerProfile {
+    pub host: String,
+    pub port: u16,
+    // Name the module was published under
+    pub module: String,
 }
 
-#[derive(Resource, Debug)]
-pub struct StoneTiles {
-    pub positions: HashSet<(u32, u32)>,
+impl ServerProfile {
+    pub fn url(&self) -> String {
+        format!("http://{}:{}", self.host, self.port)
+    }
 }
 
 // Our very important struct containing our even more important context :)
+// Inserted when pressing "Play", so it only exists while in game
 #[derive(Resource)]
 pub struct CtxWrapper {
     pub ctx: DbConnection,
 }
 
+// Chunk the area-of-interest subscription is centered on, and its handle
+#[derive(Resource, Default)]
+pub struct AreaOfInterest {
+    pub chunk: Option<IVec2>,
+    pub subscription: Option<SubscriptionHandle>,
+}
+
+// Configuration for the area-of-interest subscriptions
+pub struct AoiConfig {
+    // Side of a chunk, mirrored from the server's AOI_CHUNK_SIZE
+    pub chunk_size: f32,
+    // Chunks subscribed in every direction around the player's chunk
+    pub radius: i32,
+}
+
+// Global area-of-interest config
+pub const AOI_CONFIG: AoiConfig = AoiConfig {
+    chunk_size: 2048.0,
+    radius: 1,
+};
+
+// Configuration for reconnecting after losing the server
+pub struct ReconnectConfig {
+    // Attempts before giving up and going back to the menu
+    pub max_attempts: u32,
+    // Delay before the first attempt, doubled every attempt up to "max_delay_secs"
+    pub base_delay_secs: f32,
+    pub max_delay_secs: f32,
+}
+
+// Global reconnect config
+pub const RECONNECT_CONFIG: ReconnectConfig = ReconnectConfig {
+    max_attempts: 5,
+    base_delay_secs: 1.0,
+    max_delay_secs: 16.0,
+};
+
 //
 // === Bot defined constraints ===
 //
@@ -72,6 +121,30 @@ pub const BOT_CONFIG: BotConfig = BotConfig {
     rotation_speed: f32::to_radians(180.0),
 };
 
+//
+// === Interpolation defined constraints ===
+//
+
+// Configuration for rendering opponents, bots and opponent hooks from server snapshots
+pub struct InterpolationConfig {
+    // How far in the past remote entities are rendered, in seconds
+    pub delay_secs: f64,
+    // How long to keep moving past the newest snapshot, in seconds
+    pub max_extrapolation_secs: f64,
+    // Snapshots kept per entity
+    pub buffer_size: usize,
+    // How fast the server clock estimate follows snapshots arriving late
+    pub clock_smoothing: f64,
+}
+
+// Global interpolation config
+pub const INTERPOLATION_CONFIG: InterpolationConfig = InterpolationConfig {
+    delay_secs: 0.1,
+    max_extrapolation_secs: 0.25,
+    buffer_size: 32,
+    clock_smoothing: 0.05,
+};
+
 //
 // === Player defined constraints ===
 //
@@ -82,6 +155,14 @@ pub struct Username {
     pub name: String,
 }
 
+// Longest name the server accepts, the name field stops there
+pub const USERNAME_MAX_LENGTH: usize = 16;
+
+// Path of a file the client keeps in the user's config dir
+pub fn config_file(name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustbourn_engines").join(name))
+}
+
 #[derive(Component)]
 pub struct Player {
     pub movement_speed: f32,
@@ -104,7 +185,6 @@ pub struct PlayerGrid {
     pub block_position: HashMap<(i32, i32), Entity>,
     pub grid_size: (i32, i32),
     pub cell_size: f32,
-    pub next_free_pos: (i32, i32),
     pub capacity: u32,
     pub load: u32,
 }
@@ -122,6 +202,10 @@ pub struct PlayerConfig {
     pub rotation_speed: f32,
     pub path: &'static str,
     pub max_block_count: i32,
+    // Frames of predicted positions kept to compare with the server
+    pub prediction_frames: usize,
+    // Distance between prediction and server before the prediction is corrected
+    pub reconcile_tolerance: f32,
 }
 
 /// Global constant config for the player
@@ -130,7 +214,10 @@ pub const PLAYER_CONFIG: PlayerConfig = PlayerConfig {
     movement_speed: 300.0,
     rotation_speed: f32::to_radians(120.0),
     path: "sprites/top-view/core_long.png",
-    max_block_count: 100,
+    // Grid slots around the core, mirrored from the server's MAX_BLOCK_COUNT
+    max_block_count: 54,
+    prediction_frames: 120,
+    reconcile_tolerance: 30.0,
 };
 
 //
@@ -179,19 +266,6 @@ pub struct OpponentTrack {
 #[derive(Component)]
 pub struct LastTrackPos(pub Vec2);
 
-#[derive(Component)]
-pub struct ModifierConfig {
-    pub water: f32,
-    pub reg: f32,
-    pub stone: f32,
-}
-
-pub const MODIFIER_CONFIG: ModifierConfig = ModifierConfig {
-    water: 0.2,
-    reg: 1.0,
-    stone: 0.7,
-};
-
 //
 // === Grid defined constraints ===
 //
@@ -199,7 +273,6 @@ pub const MODIFIER_CONFIG: ModifierConfig = ModifierConfig {
 pub struct GridConfig {
     pub grid_size: (i32, i32),
     pub cell_size: f32,
-    pub next_free_pos: (i32, i32),
     pub capacity: u32,
     pub load: u32,
 }
@@ -207,7 +280,6 @@ pub struct GridConfig {
 pub const GRID_CONFIG: GridConfig = GridConfig {
     grid_size: (2, 10),
     cell_size: 79.,
-    next_free_pos: (-1, 0),
     capacity: 100,
     load: 0,
 };
@@ -301,6 +373,8 @@ pub struct HookConfig {
     pub retract_speed: f32,
     pub hook_radius: f32,
     pub player_attach_offset: Vec2,
+    // Seconds between two obstacle hits, the server enforces the same cooldown
+    pub obstacle_hit_cooldown: f32,
 }
 
 pub const HOOK_CONFIG: HookConfig = HookConfig {
@@ -314,6 +388,7 @@ pub const HOOK_CONFIG: HookConfig = HookConfig {
     retract_speed: 500.0, // Could use hook_speed here too
     hook_radius: 5.0,
     player_attach_offset: Vec2::new(0.0, 0.0),
+    obstacle_hit_cooldown: 0.1,
 };
 
 //
@@ -348,6 +423,9 @@ pub const BLOCK_CONFIG: BlockConfig = BlockConfig {
 #[derive(Component)]
 pub struct Obstacle {
     pub id: u64,
+    // Width and height from the server, a circle shape fits inside
+    pub size: Vec2,
+    pub shape: ObstacleShape,
 }
 
 // Hashset storing all spawned obstacle IDs.
@@ -360,14 +438,16 @@ pub struct SpawnedObstacles {
 pub struct ObstacleConfig {
     pub size: Vec2,
     pub count: i32,
-    pub path: &'static str,
+    pub volcano_path: &'static str,
+    pub rock_path: &'static str,
 }
 
 // Global constant config for the player
 pub const OBSTACLE_CONFIG: ObstacleConfig = ObstacleConfig {
     size: Vec2::new(400.0, 400.0), // Change obstacle size here
     count: 1,                      // Change number of obstacles on the map
-    path: "sprites/Obstacles/volcano.png",
+    volcano_path: "sprites/Obstacles/volcano.png",
+    rock_path: "sprites/Obstacles/obstacle_rock.png",
 };
 
 //
@@ -382,6 +462,8 @@ pub struct MapConfig {
     pub tile_textures: [&'static str; 86], // Change this for the number of tiles in the list
     pub image_path: &'static str,
     pub safe_zone_size: f32,
+    pub chunk_size: u32,    // Tiles per chunk side
+    pub chunk_margin: i32,  // Chunks kept loaded outside the camera view
 }
 
 /// Global constant config for the tilemap
@@ -479,6 +561,8 @@ pub const MAP_CONFIG: MapConfig = MapConfig {
     ],
     image_path: r"assets/map-flipped.png",
     safe_zone_size: 300.0,
+    chunk_size: 32,
+    chunk_margin: 1,
 };
 
 /////////////////////////////////////////////////////////
@@ -516,6 +600,7 @@ pub enum MenuState {
     Settings,
     SettingsDisplay,
     SettingsSound,
+    Servers,
     #[default]
     Disabled,
 }
@@ -533,6 +618,12 @@ pub struct OnDisplaySettingsMenuScreen;
 #[derive(Component)]
 pub struct OnSoundSettingsMenuScreen;
 
+#[derive(Component)]
+pub struct OnServersMenuScreen;
+
+#[derive(Component)] // Text showing the outcome of connecting to a server
+pub struct StatusText;
+
 #[derive(Component)] // Which is the currently selected setting
 pub struct SelectedOption;
 
@@ -540,6 +631,7 @@ pub struct SelectedOption;
 #[derive(Component)]
 pub enum MenuButtonAction {
     Play,
+    Servers,
     Settings,
     SettingsDisplay,
     SettingsSound,
@@ -3,110 +3,99 @@ use bevy::prelude::*;
 
 
 // Spacetime dependencies
-use crate::common::{CtxWrapper, Opponent, OpponentTrack};
+use crate::common::{
+    AreaOfInterest, CtxWrapper, Opponent, OpponentTrack, Player, ServerProfile, AOI_CONFIG,
+};
 use crate::opponent::*;
 use crate::{module_bindings::*, player};
-use spacetimedb_sdk::{credentials, DbContext, Error, Identity, Table};
-
-use crate::parse::*;
+use spacetimedb_sdk::{credentials, DbContext, Error, Identity, Status, Table};
 
 use crate::common::HookAttach;
 use crate::common::OpponentHookHead;
-use crate::common::{OpponentHook, Username};
+use crate::common::OpponentHook;
 use crate::hook::*;
+use crate::interpolation::{InterpolationBuffer, Snapshot};
+use crate::reconnect::{next_connection_generation, push_connection_event, ConnectionEvent};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
 
-/// The database name we chose when we published our module.
-//const DB_NAME: &str = "c200083d815ce43080deb1559d525d655b7799ec50b1552f413b372555053a1c";
-pub const DB_NAME: &str = "test";
+// How long testing a server may take before it counts as unreachable
+const CONNECTION_TEST_TIMEOUT: Duration = Duration::from_secs(3);
 
-pub fn update_player_position(ctx_wrapper: &CtxWrapper, player_transform: &Transform) {
+/// Sends the current controls, the server moves the player from them.
+pub fn send_input(ctx_wrapper: &CtxWrapper, throttle: f32, turn: f32, hook_charge: bool) {
     ctx_wrapper
         .ctx
         .reducers()
-        .update_player_position(BevyTransform {
-            coordinates: vec_2_type::Vec2 {
-                x: player_transform.translation.x,
-                y: player_transform.translation.y,
-            },
-            rotation: player_transform.rotation.to_euler(EulerRot::XYZ).2,
-            scale: vec_2_type::Vec2 { x: 0.0, y: 0.0 },
-        })
-        .unwrap();
-    //println!("{}", player_transform.rotation.to_euler(EulerRot::XYZ).2);
+        .send_input(throttle, turn, hook_charge)
+        .unwrap_or_else(|err| eprintln!("Failed to send input: {}", err));
 }
 
-// db_connection
-pub fn update_bot_position(
-    ctx_wrapper: &CtxWrapper,
-    bot_transform: &Transform,
-    bot_id: u64,
-    new_rotate_dir: f32,
-) {
-    ctx_wrapper
-        .ctx
-        .reducers()
-        .update_bot_position(
-            BevyTransform {
-                coordinates: vec_2_type::Vec2 {
-                    x: bot_transform.translation.x,
-                    y: bot_transform.translation.y,
-                },
-                rotation: bot_transform.rotation.to_euler(EulerRot::XYZ).2,
-                scale: vec_2_type::Vec2 { x: 0.0, y: 0.0 },
-            },
-            bot_id,
-            new_rotate_dir,
-        )
-        .unwrap();
-    //println!("{}", player_transform.rotation.to_euler(EulerRot::XYZ).2);
+/// Subscribes once in game, the connection was opened and the name set in the menu by "join_game".
+pub fn setup_connection(ctx_wrapper: Res<CtxWrapper>) {
+    subscribe_to_tables(&ctx_wrapper.ctx);
 }
 
-pub fn db_setup() -> DbConnection {
-    connect_to_db()
+/// Register subscriptions for the tables that are not spatial,
+/// the rest is subscribed around the player in "update_area_of_interest".
+pub fn subscribe_to_tables(ctx: &DbConnection) {
+    ctx.subscription_builder()
+        .on_applied(on_sub_applied)
+        .on_error(on_sub_error)
+        .subscribe(["SELECT * FROM world_map"]);
 }
 
-pub fn setup_connection(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
+/// Re-subscribes to the players, obstacles, bots and blocks in the chunks around the local player
+/// whenever the player enters a new chunk. The server keeps the chunk columns of every row up to date.
+pub fn update_area_of_interest(
     ctx_wrapper: Res<CtxWrapper>,
-    username: Res<Username>,
+    player_query: Query<&Transform, With<Player>>,
+    mut area: ResMut<AreaOfInterest>,
 ) {
-    // Connect to the database
-    //let ctx = connect_to_db();
-
-    subscribe_to_tables(&ctx_wrapper.ctx);
-
-    //// Register callbacks to run in re\sponse to database events
-    //register_callbacks(&ctx);
-    //use crate::common::OpponentHook;
-    //// Subscribe to SQL queries in order to construct a local partial replica of the database
-    //subscribe_to_tables(&ctx);
-    //
-    // Spawn a thread, where the connection will process messages and invoke callbacks.
-    ctx_wrapper.ctx.run_threaded();
-
-    //commands.insert_resource(CtxWrapper { ctx });
-    // Handle CLI input
-    //user_input_loop(&ctx);
-
-    // Set username
-    ctx_wrapper
+    // Own row is subscribed by identity, only known once connected
+    let Some(identity) = ctx_wrapper.ctx.try_identity() else {
+        return;
+    };
+    // Look around the spawn point until the player is spawned
+    let position = player_query
+        .get_single()
+        .map(|transform| transform.translation.truncate())
+        .unwrap_or(bevy::prelude::Vec2::ZERO);
+    let chunk = (position / AOI_CONFIG.chunk_size).floor().as_ivec2();
+    if area.chunk == Some(chunk) {
+        return;
+    }
+    area.chunk = Some(chunk);
+
+    let min = chunk - IVec2::splat(AOI_CONFIG.radius);
+    let max = chunk + IVec2::splat(AOI_CONFIG.radius);
+    let in_area = format!(
+        "chunk_x >= {} AND chunk_x <= {} AND chunk_y >= {} AND chunk_y <= {}",
+        min.x, max.x, min.y, max.y
+    );
+
+    // The old area is dropped once the new one is applied, so rows in both do not flicker
+    let previous = area.subscription.take();
+    let subscription = ctx_wrapper
         .ctx
-        .reducers()
-        .set_name(username.into_inner().name.clone());
-}
-
-/// Register subscriptions for all rows of the player tables.
-fn subscribe_to_tables(ctx: &DbConnection) {
-    ctx.subscription_builder()
-        .on_applied(on_sub_applied)
+        .subscription_builder()
+        .on_applied(move |_ctx| {
+            if let Some(previous) = previous {
+                if let Err(err) = previous.unsubscribe() {
+                    eprintln!("Failed to drop the previous area of interest: {}", err);
+                }
+            }
+        })
         .on_error(on_sub_error)
         .subscribe([
-            "SELECT * FROM player WHERE online=true",
-            "SELECT * FROM obstacle",
-            "SELECT * FROM bots",
-            "SELECT * FROM block",
+            format!("SELECT * FROM player WHERE identity = 0x{}", identity.to_hex()),
+            format!("SELECT * FROM player WHERE online = true AND {}", in_area),
+            format!("SELECT * FROM obstacle WHERE {}", in_area),
+            format!("SELECT * FROM bots WHERE {}", in_area),
+            format!("SELECT * FROM block WHERE {}", in_area),
         ]);
+    area.subscription = Some(subscription);
 }
 
 /// Our `on_subscription_applied` callback:
@@ -120,75 +109,140 @@ fn on_sub_applied(ctx: &SubscriptionEventContext) {
     println!("[DEBUG] Bots in on_sub_applied: {}", bots.len());
 }
 
+/// Our `on_subscription_error` callback: a rejected query leaves the connection up,
+/// losing the connection itself is reported by "on_disconnected".
 fn on_sub_error(_ctx: &ErrorContext, err: Error) {
     eprintln!("Subscription failed: {}", err);
-    std::process::exit(1);
 }
 
-fn connect_to_db() -> DbConnection {
-    let server_url = parse_args();
-
-    //let server_url = parse_args();
-    //println!("Server url: {:?}", server_url.to_string());
+/// Builds a connection to "profile" with the credentials saved for its module.
+/// Called when pressing "Play", and by "retry_connection" to reconnect.
+/// Every connection gets a new generation, its callbacks tag their events with it.
+pub fn connect_to_db(profile: &ServerProfile) -> Result<DbConnection, Error> {
+    let module = profile.module.clone();
+    let generation = next_connection_generation();
     match DbConnection::builder()
         // Register our `on_connect` callback, which will save our auth token.
-        .on_connect(on_connected)
-        // Register our `on_connect_error` callback, which will print a message, then exit the process.
-        .on_connect_error(on_connect_error)
-        // Our `on_disconnect` callback, which will print a message, then exit the process.
-        .on_disconnect(on_disconnected)
+        .on_connect(move |ctx, identity, token| {
+            on_connected(ctx, identity, token, &module, generation)
+        })
+        // Register our `on_connect_error` callback, which will print a message, then reconnect.
+        .on_connect_error(move |ctx, err| on_connect_error(ctx, err, generation))
+        // Our `on_disconnect` callback, which will print a message, then reconnect.
+        .on_disconnect(move |ctx, err| on_disconnected(ctx, err, generation))
         // If the user has previously connected, we'll have saved a token in the `on_connect` callback.
         // In that case, we'll load it and pass it to `with_token`,
         // so we can re-authenticate as the same `Identity`.
-        .with_token(creds_store().load().expect("Error loading credentials"))
-        .with_module_name(DB_NAME)
-        .with_uri(server_url)
+        .with_token(creds_store(&profile.module).load().expect("Error loading credentials"))
+        .with_module_name(&profile.module)
+        .with_uri(profile.url())
         .build()
     {
         Ok(db) => {
             println!("Connected to database successfully!");
-            db
-        }
-        Err(e) => {
-            eprintln!("Failed to connect to database: {:?}", e);
-            std::process::exit(1); // Exit the program gracefully
+            db.reducers
+                .on_set_name(move |ctx, name| on_name_set(ctx, name, generation));
+            Ok(db)
         }
+        Err(e) => Err(e),
     }
 }
 
-fn creds_store() -> credentials::File {
-    credentials::File::new(DB_NAME)
+/// Checks that "profile" is reachable and hosts the module. Asks the server's HTTP API about the
+/// module instead of opening a session, so no identity is created and no player joins.
+/// Blocks for up to "CONNECTION_TEST_TIMEOUT", call it off the main thread.
+pub fn test_connection(profile: &ServerProfile) -> Result<(), String> {
+    // The name goes into the request line
+    if !profile
+        .module
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("Invalid module name \"{}\"", profile.module));
+    }
+
+    let address = (profile.host.as_str(), profile.port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addresses| addresses.next())
+        .ok_or_else(|| format!("Cannot resolve {}", profile.host))?;
+    let mut stream = TcpStream::connect_timeout(&address, CONNECTION_TEST_TIMEOUT)
+        .map_err(|err| err.to_string())?;
+    stream
+        .set_read_timeout(Some(CONNECTION_TEST_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(CONNECTION_TEST_TIMEOUT)))
+        .map_err(|err| err.to_string())?;
+    write!(
+        stream,
+        "GET /v1/database/{} HTTP/1.1\r\nHost: {}:{}\r\nConnection: close\r\n\r\n",
+        profile.module, profile.host, profile.port
+    )
+    .map_err(|err| err.to_string())?;
+
+    let mut status_line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut status_line)
+        .map_err(|err| err.to_string())?;
+    match status_line.split_whitespace().nth(1) {
+        Some("200") => Ok(()),
+        Some("404") => Err(format!("Module \"{}\" not found", profile.module)),
+        Some(code) => Err(format!("Server answered with status {}", code)),
+        None => Err("Not a SpacetimeDB server".to_string()),
+    }
+}
+
+fn creds_store(module: &str) -> credentials::File {
+    credentials::File::new(module)
 }
 
 /// Our `on_connect` callback: save our credentials to a file.
-fn on_connected(_ctx: &DbConnection, _identity: Identity, token: &str) {
-    if let Err(e) = creds_store().save(token) {
+fn on_connected(
+    _ctx: &DbConnection,
+    _identity: Identity,
+    token: &str,
+    module: &str,
+    generation: u64,
+) {
+    if let Err(e) = creds_store(module).save(token) {
         eprintln!("Failed to save credentials: {:?}", e);
     }
+    push_connection_event(generation, ConnectionEvent::Connected);
 }
 
-/// Our `on_connect_error` callback: print the error, then exit the process.
-fn on_connect_error(_ctx: &ErrorContext, err: Error) {
+/// Our `on_set_name` callback: report whether the server accepted our name.
+fn on_name_set(ctx: &ReducerEventContext, _name: &String, generation: u64) {
+    if ctx.event.caller_identity != ctx.identity() {
+        return;
+    }
+    let event = match &ctx.event.status {
+        Status::Committed => ConnectionEvent::NameAccepted,
+        Status::Failed(reason) => ConnectionEvent::NameRejected(reason.to_string()),
+        Status::OutOfEnergy => ConnectionEvent::NameRejected("Server is out of energy".to_string()),
+    };
+    push_connection_event(generation, event);
+}
+
+/// Our `on_connect_error` callback: print the error, then try again.
+fn on_connect_error(_ctx: &ErrorContext, err: Error, generation: u64) {
     eprintln!("Connection error: {:?}", err);
-    std::process::exit(1);
+    push_connection_event(generation, ConnectionEvent::Lost(format!("{:?}", err)));
 }
 
-/// Our `on_disconnect` callback: print a note, then exit the process.
-fn on_disconnected(_ctx: &ErrorContext, err: Option<Error>) {
-    if let Some(err) = err {
-        eprintln!("Disconnected: {}", err);
-        std::process::exit(1);
-    } else {
-        println!("Disconnected.");
-        std::process::exit(0);
-    }
+/// Our `on_disconnect` callback: print a note, then reconnect.
+fn on_disconnected(_ctx: &ErrorContext, err: Option<Error>, generation: u64) {
+    let reason = match err {
+        Some(err) => err.to_string(),
+        None => "closed by the server".to_string(),
+    };
+    eprintln!("Disconnected: {}", reason);
+    push_connection_event(generation, ConnectionEvent::Lost(reason));
 }
 
 pub fn update_opponent_positions(
     ctx_wrapper: Res<CtxWrapper>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    mut query: Query<(&mut Transform, &Opponent)>,
+    mut query: Query<(&mut InterpolationBuffer, &Opponent)>,
 ) {
     let players = ctx_wrapper.ctx.db.player().iter().collect::<Vec<_>>();
     let local_player_id = ctx_wrapper.ctx.identity(); //Get local player's ID
@@ -211,32 +265,18 @@ pub fn update_opponent_positions(
         update_opponent(
             &mut query,
             &player.identity,
-            player.position.coordinates.x,
-            player.position.coordinates.y,
-            player.position.rotation,
+            Snapshot::new(
+                player.updated_at,
+                player.position.coordinates.x,
+                player.position.coordinates.y,
+                player.position.rotation,
+                0.0,
+            ),
         );
     }
     //println!("");
 }
 
-pub fn load_obstacles(ctx_wrapper: &CtxWrapper) -> Vec<(f32, f32, u64, u32)> {
-    let obstacles: Vec<(f32, f32, u64, u32)> = ctx_wrapper
-        .ctx
-        .db
-        .obstacle()
-        .iter()
-        .map(|obstacle| {
-            (
-                obstacle.position.x,
-                obstacle.position.y,
-                obstacle.id,
-                obstacle.hp,
-            )
-        })
-        .collect();
-    obstacles
-}
-
 pub fn load_bots(ctx_wrapper: &CtxWrapper) -> Vec<(f32, f32, u64)> {
     println!("[DEBUG] object)",);
     let bots: Vec<(f32, f32, u64)> = ctx_wrapper
@@ -313,10 +353,7 @@ pub fn update_opponent_hooks(
     /*mut hook_query: Query<(&mut Sprite, &mut Transform, &OpponentHook), With<OpponentHook>>,
      mut head_query: Query<(&mut Transform, &HookAttach, &OpponentHookHead)>,
     */
-    mut queries: ParamSet<(
-        Query<(&mut Sprite, &mut Transform, &OpponentHook), With<OpponentHook>>,
-        Query<(&mut Transform, &HookAttach, &OpponentHookHead)>,
-    )>,
+    mut hook_query: Query<(&mut InterpolationBuffer, &OpponentHook)>,
     existing_hooks_query: Query<&OpponentHook>,
     existing_heads_query: Query<&OpponentHookHead>,
     despawn_query: Query<(Entity, &OpponentHook)>,
@@ -339,15 +376,15 @@ pub fn update_opponent_hooks(
         );
 
         update_opponent_hook(
-            /*&mut hook_query,
-            &mut head_query,*/
-            &mut queries,
+            &mut hook_query,
             &player_id,
-            player.hook.position.x,
-            player.hook.position.y,
-            player.hook.rotation,
-            player.hook.width,
-            player.hook.height,
+            Snapshot::new(
+                player.updated_at,
+                player.hook.position.x,
+                player.hook.position.y,
+                player.hook.rotation,
+                player.hook.height,
+            ),
         );
     }
 }
@@ -6,14 +6,13 @@ use crate::{
     common::{
         AttachedBlock, Block, CtxWrapper, Hook, HookCharge, HookRange, Obstacle, OpponentHook,OpponentHookHead,
         Player, PlayerAttach, PlayerGrid, BLOCK_CONFIG, HOOK_CONFIG, OBSTACLE_CONFIG,HookAttach,
-        PLAYER_CONFIG, HookHead, HookTimer, HookCooldown
+        HookHead, HookTimer, HookCooldown
     },
-    db_connection::load_obstacles,
-    grid::{increment_grid_pos, get_block_count, get_bot_block_count},
+    interpolation::{InterpolationBuffer, Snapshot},
     opponent,
+    player_attach::obstacle_distance,
 };
 use bevy::prelude::{Vec2, Vec3};
-use rand::Rng;
 use bevy::{prelude::*, transform};
 use spacetimedb_sdk::{
     credentials, DbContext, Error, Event, Identity, Status, Table, TableWithPrimaryKey,
@@ -89,7 +88,6 @@ pub fn hook_controls(
         Query<(&mut Transform, &HookAttach), With<HookHead>>,
     )>,
     time: Res<Time>,
-    ctx: Res<CtxWrapper>,
 ) {
     let mut range_update_info: Option<(Vec3, Quat, f32)> = None;
     let mut rope_tip_position: Option<(Vec3, Quat, f32)> = None;
@@ -130,11 +128,6 @@ pub fn hook_controls(
 
             sprite.custom_size = Some(Vec2::new(sprite.custom_size.unwrap().x, next_height));
 
-            ctx.ctx
-                .reducers()
-                .update_hook_movement(ctx.ctx.identity(), sprite.custom_size.unwrap().x, next_height)
-                .unwrap();
-
             if (next_height - charge.target_length).abs() < 0.1 {
                 charge.target_length = 0.0;
             }
@@ -149,11 +142,6 @@ pub fn hook_controls(
             transform.translation -= offset;
 
             sprite.custom_size = Some(Vec2::new(sprite.custom_size.unwrap().x, next_height));
-
-            ctx.ctx
-                .reducers()
-                .update_hook_movement(ctx.ctx.identity(), sprite.custom_size.unwrap().x, next_height)
-                .unwrap();
         }
 
         // Final tip position for HookHead sync
@@ -195,21 +183,21 @@ pub fn hook_cooldown_system(
 
 pub fn hook_collision_system(
     hook_query: Query<(&Transform, &Sprite), (With<Hook>, Without<Block>)>,
-    mut block_query: Query<(Entity, &mut Transform, Option<&mut AttachedBlock>), (With<Block>)>,
-    mut player_query: Query<
-        (Entity, &Transform, &mut Player, &mut PlayerGrid),
-        (Without<Hook>, Without<Block>),
-    >,
-    mut grid_query: Query<&mut PlayerGrid, Without<Player>>,
+    block_query: Query<(Entity, &Transform, Option<&AttachedBlock>), With<Block>>,
+    player_query: Query<(Entity, &PlayerGrid), (With<Player>, Without<Hook>, Without<Block>)>,
     attachable_blocks: Query<&PlayerAttach>,
-    mut commands: Commands,
     ctx_wrapper: Res<CtxWrapper>,
-    mut spawned_blocks: ResMut<SpawnedBlocks>,
+    spawned_blocks: Res<SpawnedBlocks>,
     time: Res<Time>,
     mut hook_timer: ResMut<HookTimer>,
 ) {
     // Tick cooldown
     hook_timer.0.tick(time.delta());
+    // 0.5 second cooldown, the server enforces the same cooldown
+    if !hook_timer.0.finished() {
+        return;
+    }
+
     let (hook_transform, sprite) = if let Ok(val) = hook_query.get_single() {
         val
     } else {
@@ -220,10 +208,8 @@ pub fn hook_collision_system(
         + hook_transform.rotation
             * bevy::prelude::Vec3::new(0.0, sprite.custom_size.unwrap().y, 0.0);
 
-    if let Ok((player_entity, _player_transform, mut player, mut grid)) =
-        player_query.get_single_mut()
-    {
-        for (block_entity, mut block_transform, mut attach_link_option) in block_query.iter_mut() {
+    if let Ok((player_entity, grid)) = player_query.get_single() {
+        for (block_entity, block_transform, attach_link_option) in block_query.iter() {
             let block_radius = BLOCK_CONFIG.size.x.min(BLOCK_CONFIG.size.y) / 2.0;
             let hook_radius = 5.0; // Hook tip radius
             let collision_distance = block_radius + hook_radius;
@@ -231,136 +217,33 @@ pub fn hook_collision_system(
             if hook_tip
                 .truncate()
                 .distance(block_transform.translation.truncate())
-                < collision_distance
+                >= collision_distance
             {
-                // Check if block already attached
-                if grid.load < grid.capacity
-                    && attachable_blocks.get(block_entity).is_err()
-                    && player.block_count < PLAYER_CONFIG.max_block_count
-                {
-                    let nextpos = grid.find_next_free_pos().expect("No position found");
-
-                    // NOTE: If block is attached or not. (Option<AttachedBlock>)
-                    if let Some(mut attach_link) = attach_link_option {
-                        // Remove grid_pos/block entity tuple from target grid
-                        //if let Ok((_target_ent, _target_tran, _target_play, mut target_grid)) =
-                        //    player_query.get(attach_link.player_entity)
-                        //{
-                        //    target_grid.block_position.remove(&attach_link.grid_offset);
-                        //    println!(
-                        //        "Stole block at: ({}, {}) from player",
-                        //        attach_link.grid_offset.0, attach_link.grid_offset.1
-                        //    );
-                        //     } else
-
-                        // 0.5 second cooldown
-                        if !hook_timer.0.finished(){
-                            return;
-                        }
-
-                        // Weighted combat mechanism
-                        if let Some(block_id) = spawned_blocks.entities.get(&block_entity){
-                            if let Some(block_from_db) = ctx_wrapper.ctx.db.block().id().find(&block_id){
-                                // Get block owner
-                                let block_owner = block_from_db.owner;
-                                // If player
-                                if let OwnerType::Player(owner_identity) = block_owner{
-                                    // Get block counts
-                                    let opp_block_count = get_block_count(owner_identity, &ctx_wrapper, &spawned_blocks);
-                                    let player_block_count = get_block_count(ctx_wrapper.ctx.identity(), &ctx_wrapper, &spawned_blocks);
-                                    if !weighted_combat(player_block_count, opp_block_count){
-                                        continue; // Do not take block
-                                    }
-                                } else if let OwnerType::Bot(bot_id) = block_owner{ // If bot
-                                    // Get block counts
-                                    let bot_block_count = get_bot_block_count(bot_id, &ctx_wrapper, &spawned_blocks);
-                                    let player_block_count = get_block_count(ctx_wrapper.ctx.identity(), &ctx_wrapper, &spawned_blocks);
-                                    if !weighted_combat(player_block_count, bot_block_count){
-                                        continue; // Do not take block
-                                    }
-                                }
-                                hook_timer.0.reset();
-                            }
-                        }
-
-                        if let Ok(mut target_grid) = grid_query.get_mut(attach_link.player_entity) {
-                            target_grid.block_position.remove(&attach_link.grid_offset);
-                            println!(
-                                "Stole block at: ({}, {}) from bot or opp",
-                                attach_link.grid_offset.0, attach_link.grid_offset.1
-                            );
-                        }
-
-                        // Add grid_pos/block entity tuple to player grid
-                        grid.block_position.insert(nextpos, block_entity);
-                        println!("Add new block in gridpos: ({}, {})", nextpos.0, nextpos.1);
-
-                        // Update block ownership locally
-                        attach_link.player_entity = player_entity;
-                        attach_link.grid_offset = nextpos;
-
-                        // Update block ownership on server
-                        let id_block_db = spawned_blocks
-                            .entities
-                            .get(&block_entity)
-                            .expect("Failed lookup for block Entity->ServerID");
-                        ctx_wrapper.ctx.reducers().update_block_owner(
-                            id_block_db.clone(),
-                            OwnerType::Player(ctx_wrapper.ctx.identity()),
-                            nextpos.0,
-                            nextpos.1,
-                        ).unwrap();
-                    } else {
-                        commands.entity(block_entity).insert(AttachedBlock {
-                            grid_offset: nextpos,
-                            player_entity: player_entity,
-                        });
-
-                        // Update the server as well
-                        let id_block_db = spawned_blocks
-                            .entities
-                            .get(&block_entity)
-                            .expect("Failed lookup for block Entity->ServerID");
-
-                        ctx_wrapper.ctx.reducers().update_block_owner(
-                            id_block_db.clone(),
-                            OwnerType::Player(ctx_wrapper.ctx.identity()),
-                            nextpos.0,
-                            nextpos.1,
-                        ).unwrap();
-                    }
-                    grid.block_position.insert(nextpos, block_entity);
-                    block_transform.translation.x += 200_000.;
-                    println!(
-                        "Attach at gridpos ({}, {})",
-                        grid.next_free_pos.0, grid.next_free_pos.1
-                    );
-                    // increment grid pos
-                    //increment_grid_pos(&mut grid);
-
-                    player.block_count += 1;
-                    ctx_wrapper.ctx.reducers().update_owner_grid(player.block_count, grid.next_free_pos.0, grid.next_free_pos.1).unwrap();
-                }
+                continue;
             }
-        }
-    }
-}
 
-fn weighted_combat(
-    player_block_count: i32,
-    opps_block_count: i32,
-) -> bool {
-    
-    let total_block_count = player_block_count + opps_block_count;
-    if total_block_count == 0 {
-        return true; // No blocks, player wins by default
-    }
-
-    let player_weight = (player_block_count + 1) as f32/ (total_block_count + 2) as f32;
+            // Skip blocks already attached to the player
+            if attach_link_option.is_some_and(|link| link.player_entity == player_entity) {
+                continue;
+            }
 
-    let mut rng = rand::rng();
-    rng.random_range(0.0..1.0) // Generate a random number between 0 and 1
-    < (player_weight + 1 as f32) // If the roll is less than the player's weight, they win
+            if grid.load < grid.capacity && attachable_blocks.get(block_entity).is_err() {
+                let Some(block_id) = spawned_blocks.entities.get(&block_entity) else {
+                    continue;
+                };
+
+                // Only request the steal. The server rolls the weighted combat, picks the grid
+                // position and the grid systems pick up the resulting block row update.
+                ctx_wrapper
+                    .ctx
+                    .reducers()
+                    .steal_block(*block_id)
+                    .unwrap_or_else(|err| eprintln!("Failed to steal block: {}", err));
+                hook_timer.0.reset();
+                return;
+            }
+        }
+    }
 }
 
 pub fn spawn_opponent_hook(
@@ -400,6 +283,7 @@ pub fn spawn_opponent_hook(
         },
         Transform::from_xyz(x, y, 5.0),
         OpponentHook { id: *opponent_id },
+        InterpolationBuffer::default(),
     ));
 
     commands.spawn((
@@ -433,46 +317,19 @@ pub fn update_opponent_hook(
         }
     }
 }*/
+/// Buffers the latest hook of an opponent, "interpolate_opponent_hooks" renders it slightly in the past.
 pub fn update_opponent_hook(
-    /*hook_query: &mut Query<(&mut Sprite, &mut Transform, &OpponentHook), With<OpponentHook>>,
-    head_query: &mut Query<(&mut Transform, &HookAttach, &OpponentHookHead)>,
-    */
-    queries: &mut ParamSet<(
-        Query<(&mut Sprite, &mut Transform, &OpponentHook), With<OpponentHook>>,
-        Query<(&mut Transform, &HookAttach, &OpponentHookHead)>,
-    )>,
+    query: &mut Query<(&mut InterpolationBuffer, &OpponentHook)>,
     id: &Identity,
-    x: f32,
-    y: f32,
-    rotation: f32,
-    width: f32,
-    height: f32,
+    snapshot: Snapshot,
 ) {
-    let mut matched_rotation = None;
-
-    for (mut sprite, mut transform, hook) in queries.p0().iter_mut() {
+    for (mut buffer, hook) in query.iter_mut() {
         if hook.id == *id {
-            transform.translation.x = x;
-            transform.translation.y = y;
-            let rot = Quat::from_rotation_z(rotation).normalize();
-            transform.rotation = rot;
-            sprite.custom_size = Some(Vec2::new(width, height));
-            matched_rotation = Some((transform.translation, rot, height));
-        }
-    }
-
-    if let Some((base_pos, rot, hook_length)) = matched_rotation {
-        let hook_tip = base_pos + rot * Vec3::Y * hook_length;
-        for (mut head_transform, attach, head) in queries.p1().iter_mut() {
-            if head.id == *id {
-                head_transform.translation = hook_tip + rot * Vec3::from((attach.offset, 0.0));
-                head_transform.rotation = rot;
-            }
+            buffer.push(snapshot);
         }
     }
 }
 
-
 pub fn despawn_opponent_hooks(
     mut commands: Commands,
     ctx_wrapper: Res<CtxWrapper>,
@@ -529,8 +386,15 @@ pub fn handle_obstacle_hit(
     ctx_wrapper: Res<CtxWrapper>,
     hook_query: Query<(&Transform, &Sprite), With<Hook>>,
     obstacle_query: Query<(&Obstacle, &Transform)>,
+    time: Res<Time>,
+    mut since_last_hit: Local<f32>,
 ) {
-    let obstacle_radius = OBSTACLE_CONFIG.size.x.min(OBSTACLE_CONFIG.size.y) / 2.0;
+    // Only send hits as often as the server accepts them
+    *since_last_hit += time.delta_secs();
+    if *since_last_hit < HOOK_CONFIG.obstacle_hit_cooldown {
+        return;
+    }
+
     let hook_radius = 6.0;
 
     // Ensure hook_query and obstacle_query contain valid entities
@@ -543,13 +407,14 @@ pub fn handle_obstacle_hit(
             hook_transform.translation + hook_transform.up() * (hook_sprite.custom_size.unwrap().y); // tip = base + height
 
         for (obstacle, obstacle_transform) in &obstacle_query {
-            let obstacle_pos = obstacle_transform.translation.truncate();
-            let obstacle_pos_3d = bevy::prelude::Vec3::new(obstacle_pos.x, obstacle_pos.y, 0.0);
-            let distance = hook_tip.distance(obstacle_pos_3d);
-
-            if distance < (hook_radius + obstacle_radius) {
-                // Ask SpaceTimeDB to handle the damage
-                let _ = ctx_wrapper.ctx.reducers.damage_obstacle(obstacle.id, 1);
+            let distance =
+                obstacle_distance(hook_tip.truncate(), obstacle_transform, obstacle);
+
+            if distance < hook_radius {
+                // Ask SpaceTimeDB to handle the damage, the server decides how much
+                let _ = ctx_wrapper.ctx.reducers.damage_obstacle(obstacle.id);
+                *since_last_hit = 0.0;
+                return;
             }
         }
     }
@@ -0,0 +1,347 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::Timestamp;
+use std::collections::VecDeque;
+use std::f32::consts::{PI, TAU};
+
+use crate::common::{
+    Bot, CtxWrapper, HookAttach, Opponent, OpponentHook, OpponentHookHead, INTERPOLATION_CONFIG,
+};
+use crate::module_bindings::{BotsTableAccess, PlayerTableAccess};
+
+/// State of a remote entity at a server time.
+#[derive(Clone, Copy, Debug)]
+pub struct Snapshot {
+    // Server time in seconds since the unix epoch
+    pub time: f64,
+    pub position: Vec2,
+    pub rotation: f32,
+    // Hook length, 0 for cores
+    pub length: f32,
+}
+
+impl Snapshot {
+    pub fn new(timestamp: Timestamp, x: f32, y: f32, rotation: f32, length: f32) -> Self {
+        Snapshot {
+            time: timestamp.to_micros_since_unix_epoch() as f64 / 1_000_000.0,
+            position: Vec2::new(x, y),
+            rotation,
+            length,
+        }
+    }
+}
+
+/// Recent snapshots of a remote entity, oldest first. Rendered slightly in the past so there
+/// is usually a snapshot on each side of the render time to interpolate between.
+#[derive(Component, Default)]
+pub struct InterpolationBuffer {
+    snapshots: VecDeque<Snapshot>,
+}
+
+impl InterpolationBuffer {
+    /// Adds a snapshot, ignored when it is not newer than the last one.
+    pub fn push(&mut self, snapshot: Snapshot) {
+        if self
+            .snapshots
+            .back()
+            .is_some_and(|last| last.time >= snapshot.time)
+        {
+            return;
+        }
+        self.snapshots.push_back(snapshot);
+        if self.snapshots.len() > INTERPOLATION_CONFIG.buffer_size {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Drops every snapshot, the next sample jumps straight to the next pushed one.
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+
+    /// State at "time". Between two snapshots it is interpolated, past the newest one it is
+    /// extrapolated from the last two for at most "max_extrapolation_secs".
+    pub fn sample(&self, time: f64) -> Option<Snapshot> {
+        let newest = *self.snapshots.back()?;
+        if time >= newest.time {
+            let Some(previous) = self.snapshots.iter().rev().nth(1) else {
+                return Some(newest);
+            };
+            let ahead = (time - newest.time).min(INTERPOLATION_CONFIG.max_extrapolation_secs);
+            let t = 1.0 + (ahead / (newest.time - previous.time)) as f32;
+            return Some(lerp_snapshot(previous, &newest, t));
+        }
+
+        let next_index = self.snapshots.iter().position(|s| s.time > time)?;
+        if next_index == 0 {
+            return self.snapshots.front().copied();
+        }
+        let previous = &self.snapshots[next_index - 1];
+        let next = &self.snapshots[next_index];
+        let t = ((time - previous.time) / (next.time - previous.time)) as f32;
+        Some(lerp_snapshot(previous, next, t))
+    }
+}
+
+/// Linear blend of two snapshots, "t" above 1 extrapolates. Rotations take the short way around.
+fn lerp_snapshot(a: &Snapshot, b: &Snapshot, t: f32) -> Snapshot {
+    let rotation_diff = (b.rotation - a.rotation + PI).rem_euclid(TAU) - PI;
+    Snapshot {
+        time: a.time + (b.time - a.time) * t as f64,
+        position: a.position.lerp(b.position, t),
+        rotation: a.rotation + rotation_diff * t,
+        length: (a.length + (b.length - a.length) * t).max(0.0),
+    }
+}
+
+/// Estimate of the server clock. The offset follows the fastest arriving snapshot,
+/// and otherwise drifts slowly so that late snapshots do not make the render time jump.
+#[derive(Resource, Default)]
+pub struct ServerClock {
+    offset: Option<f64>,
+    newest: f64,
+}
+
+impl ServerClock {
+    fn observe(&mut self, server_secs: f64, local_secs: f64) {
+        if server_secs <= self.newest {
+            return;
+        }
+        self.newest = server_secs;
+
+        let sample = server_secs - local_secs;
+        self.offset = Some(match self.offset {
+            Some(offset) if sample < offset => {
+                offset + (sample - offset) * INTERPOLATION_CONFIG.clock_smoothing
+            }
+            _ => sample,
+        });
+    }
+
+    /// Server time remote entities are rendered at, "delay_secs" behind the estimated server time.
+    pub fn render_time(&self, local_secs: f64) -> Option<f64> {
+        self.offset
+            .map(|offset| local_secs + offset - INTERPOLATION_CONFIG.delay_secs)
+    }
+}
+
+/// Follows the newest timestamp in the player and bot tables.
+pub fn update_server_clock(
+    ctx_wrapper: Res<CtxWrapper>,
+    time: Res<Time>,
+    mut clock: ResMut<ServerClock>,
+) {
+    let players = ctx_wrapper.ctx.db.player().iter().map(|p| p.updated_at);
+    let bots = ctx_wrapper.ctx.db.bots().iter().map(|b| b.updated_at);
+    if let Some(newest) = players.chain(bots).max() {
+        let server_secs = newest.to_micros_since_unix_epoch() as f64 / 1_000_000.0;
+        clock.observe(server_secs, time.elapsed_secs_f64());
+    }
+}
+
+/// Moves opponents and bots to their buffered state at the render time.
+pub fn interpolate_remote_entities(
+    mut query: Query<(&mut Transform, &InterpolationBuffer), Or<(With<Opponent>, With<Bot>)>>,
+    clock: Res<ServerClock>,
+    time: Res<Time>,
+) {
+    let Some(render_time) = clock.render_time(time.elapsed_secs_f64()) else {
+        return;
+    };
+
+    for (mut transform, buffer) in query.iter_mut() {
+        if let Some(snapshot) = buffer.sample(render_time) {
+            transform.translation.x = snapshot.position.x;
+            transform.translation.y = snapshot.position.y;
+            transform.rotation = Quat::from_rotation_z(snapshot.rotation);
+        }
+    }
+}
+
+/// Moves opponent hooks, their length and their heads to the buffered state at the render time.
+pub fn interpolate_opponent_hooks(
+    mut queries: ParamSet<(
+        Query<(
+            &mut Sprite,
+            &mut Transform,
+            &InterpolationBuffer,
+            &OpponentHook,
+        )>,
+        Query<(&mut Transform, &HookAttach, &OpponentHookHead)>,
+    )>,
+    clock: Res<ServerClock>,
+    time: Res<Time>,
+) {
+    let Some(render_time) = clock.render_time(time.elapsed_secs_f64()) else {
+        return;
+    };
+
+    let mut tips = Vec::new();
+    for (mut sprite, mut transform, buffer, hook) in queries.p0().iter_mut() {
+        let Some(snapshot) = buffer.sample(render_time) else {
+            continue;
+        };
+        let rotation = Quat::from_rotation_z(snapshot.rotation);
+        transform.translation.x = snapshot.position.x;
+        transform.translation.y = snapshot.position.y;
+        transform.rotation = rotation;
+        if let Some(size) = sprite.custom_size.as_mut() {
+            size.y = snapshot.length;
+        }
+        let tip = transform.translation + rotation * Vec3::Y * snapshot.length;
+        tips.push((hook.id, tip, rotation));
+    }
+
+    for (mut head_transform, attach, head) in queries.p1().iter_mut() {
+        if let Some((_, tip, rotation)) = tips.iter().find(|(id, _, _)| *id == head.id) {
+            head_transform.translation = *tip + *rotation * Vec3::from((attach.offset, 0.0));
+            head_transform.rotation = *rotation;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(time: f64, x: f32, rotation: f32) -> Snapshot {
+        Snapshot {
+            time,
+            position: Vec2::new(x, 0.0),
+            rotation,
+            length: 0.0,
+        }
+    }
+
+    fn buffer(snapshots: &[Snapshot]) -> InterpolationBuffer {
+        let mut buffer = InterpolationBuffer::default();
+        for snapshot in snapshots {
+            buffer.push(*snapshot);
+        }
+        buffer
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn sample_interpolates_between_snapshots() {
+        let buffer = buffer(&[
+            snapshot(1.0, 0.0, 0.0),
+            snapshot(2.0, 10.0, 1.0),
+            snapshot(3.0, 30.0, 1.0),
+        ]);
+
+        let sample = buffer.sample(1.5).unwrap();
+        assert_close(sample.position.x, 5.0);
+        assert_close(sample.rotation, 0.5);
+        let sample = buffer.sample(2.25).unwrap();
+        assert_close(sample.position.x, 15.0);
+    }
+
+    #[test]
+    fn sample_before_first_snapshot_holds_the_oldest() {
+        let buffer = buffer(&[snapshot(1.0, 0.0, 0.0), snapshot(2.0, 10.0, 0.0)]);
+
+        let sample = buffer.sample(0.5).unwrap();
+        assert_eq!(sample.time, 1.0);
+        assert_close(sample.position.x, 0.0);
+    }
+
+    #[test]
+    fn sample_after_last_snapshot_extrapolates_up_to_the_limit() {
+        let buffer = buffer(&[snapshot(1.0, 0.0, 0.0), snapshot(2.0, 10.0, 0.0)]);
+
+        assert_close(buffer.sample(2.1).unwrap().position.x, 11.0);
+        // Capped at "max_extrapolation_secs" past the newest snapshot
+        let capped = 10.0 + 10.0 * INTERPOLATION_CONFIG.max_extrapolation_secs as f32;
+        assert_close(buffer.sample(10.0).unwrap().position.x, capped);
+    }
+
+    #[test]
+    fn sample_with_one_snapshot_holds_it() {
+        let buffer = buffer(&[snapshot(1.0, 4.0, 0.0)]);
+
+        assert_close(buffer.sample(0.0).unwrap().position.x, 4.0);
+        assert_close(buffer.sample(5.0).unwrap().position.x, 4.0);
+    }
+
+    #[test]
+    fn sample_of_empty_buffer_is_none() {
+        assert!(InterpolationBuffer::default().sample(1.0).is_none());
+    }
+
+    #[test]
+    fn rotation_takes_the_short_way_around() {
+        let a = snapshot(0.0, 0.0, PI - 0.1);
+        let b = snapshot(1.0, 0.0, -PI + 0.1);
+
+        // Halfway through the wrap, not back through 0
+        let halfway = lerp_snapshot(&a, &b, 0.5).rotation;
+        assert_close(halfway.sin(), 0.0);
+        assert!(halfway.cos() < 0.0);
+        let near_end = lerp_snapshot(&a, &b, 0.75).rotation;
+        assert_close(near_end, PI + 0.05);
+    }
+
+    #[test]
+    fn push_ignores_snapshots_that_are_not_newer() {
+        let mut buffer = buffer(&[snapshot(2.0, 0.0, 0.0)]);
+        buffer.push(snapshot(2.0, 5.0, 0.0));
+        buffer.push(snapshot(1.0, 5.0, 0.0));
+
+        assert_eq!(buffer.snapshots.len(), 1);
+        assert_close(buffer.sample(2.0).unwrap().position.x, 0.0);
+    }
+
+    #[test]
+    fn push_evicts_the_oldest_snapshot_when_full() {
+        let extra = 5;
+        let mut buffer = InterpolationBuffer::default();
+        for i in 0..INTERPOLATION_CONFIG.buffer_size + extra {
+            buffer.push(snapshot(i as f64, i as f32, 0.0));
+        }
+
+        assert_eq!(buffer.snapshots.len(), INTERPOLATION_CONFIG.buffer_size);
+        assert_eq!(buffer.snapshots.front().unwrap().time, extra as f64);
+    }
+
+    #[test]
+    fn server_clock_follows_the_fastest_snapshot() {
+        let mut clock = ServerClock::default();
+        assert!(clock.render_time(0.0).is_none());
+
+        clock.observe(100.0, 10.0);
+        let render_time = clock.render_time(10.0).unwrap();
+        assert!((render_time - (100.0 - INTERPOLATION_CONFIG.delay_secs)).abs() < 1e-9);
+
+        // A snapshot arriving faster than before moves the offset at once
+        clock.observe(101.0, 10.5);
+        assert!(
+            (clock.render_time(10.5).unwrap() - (101.0 - INTERPOLATION_CONFIG.delay_secs)).abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn server_clock_drifts_slowly_for_late_snapshots() {
+        let mut clock = ServerClock::default();
+        clock.observe(100.0, 10.0);
+
+        // One second late, the offset only moves by "clock_smoothing" of it
+        clock.observe(101.0, 12.0);
+        let offset = clock.render_time(0.0).unwrap() + INTERPOLATION_CONFIG.delay_secs;
+        let expected = 90.0 - INTERPOLATION_CONFIG.clock_smoothing;
+        assert!((offset - expected).abs() < 1e-9);
+
+        // Snapshots not newer than the newest one are ignored
+        clock.observe(100.5, 0.0);
+        let unchanged = clock.render_time(0.0).unwrap() + INTERPOLATION_CONFIG.delay_secs;
+        assert!((unchanged - expected).abs() < 1e-9);
+    }
+}
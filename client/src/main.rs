@@ -2,7 +2,6 @@
 use bevy::{app::AppExit, color::palettes::css::CRIMSON, prelude::*};
 use bevy::{prelude::*, ui::update};
 use bevy_ecs_tilemap::prelude::*;
-use noisy_bevy::simplex_noise_2d; // For map generation. May be temporary
 
 mod block;
 mod bots;
@@ -12,6 +11,7 @@ mod db_connection;
 mod edit_menu;
 mod grid;
 mod hook;
+mod interpolation;
 mod leaderboard;
 mod map;
 mod module_bindings;
@@ -21,6 +21,8 @@ mod opponent;
 mod parse;
 mod player;
 mod player_attach;
+mod reconnect;
+mod server_browser;
 mod start_menu;
 mod track_spawner;
 
@@ -47,16 +49,26 @@ use bots::{render_bots_from_db, spawn_bot_blocks, spawn_bots};
 use camera::{camera_follow, setup_camera};
 use common::*;
 use db_connection::{
-    db_setup, setup_connection, update_opponent_hooks, despawn_opponent_hooks, update_opponent_positions,
+    setup_connection, update_area_of_interest, update_opponent_hooks, despawn_opponent_hooks, update_opponent_positions,
     update_opponent_tracks,
 };
-use grid::{balance_opponents_grid, balance_player_grid, check_grid_connectivity};
+use grid::{balance_opponents_grid, balance_player_grid};
 
 use hook::{handle_obstacle_hit, hook_cooldown_system};
+use interpolation::{
+    interpolate_opponent_hooks, interpolate_remote_entities, update_server_clock, ServerClock,
+};
 use leaderboard::{spawn_leaderboard, update_leaderboard_from_db};
-use map::setup_tilemap;
+use map::{setup_tilemap, stream_tilemap_chunks};
 use opponent::{despawn_opponents, setup_blocks_opponent, spawn_opponent_tracks_system};
-use player::{player_movement, setup_blocks_player, setup_player};
+use reconnect::{
+    handle_connection_events, leave_when_removed, retry_connection, teardown_offline_game,
+    update_reconnect_overlay, ReconnectState,
+};
+use player::{
+    player_movement, reconcile_player_position, send_player_input, setup_blocks_player,
+    setup_player,
+};
 use track_spawner::{spawn_tracks_system, track_lifetime_system};
 
 fn main() {
@@ -76,22 +88,16 @@ fn main() {
         .insert_resource(Volume(7))
         .add_plugins((splash_plugin, menu_plugin, game_plugin)) //edit_plugin
         .add_systems(Startup, (setup_camera,).chain())
+        .add_systems(OnEnter(GameState::Menu), teardown_offline_game)
         .add_systems(
             OnEnter(GameState::Game),
-            (
-                setup_connection,
-                setup_player,
-                setup_tilemap,
-                setup_hook,
-                spawn_tags,
-                spawn_leaderboard,
-            )
-                .chain(),
+            (setup_connection, setup_hook, spawn_leaderboard).chain(),
         )
         .add_systems(
             Update,
             (
                 player_movement,
+                reconcile_player_position.after(player_movement),
                 update_block,
                 confine_player_movement,
                 camera_follow,
@@ -108,7 +114,6 @@ fn main() {
                 spawn_tracks_system,
                 spawn_opponent_tracks_system,
                 update_opponent_tracks,
-                check_grid_connectivity,
             )
                 .run_if(in_game_or_edit),
         )
@@ -119,7 +124,28 @@ fn main() {
                 balance_player_grid,
                 balance_opponents_grid,
                 hook_cooldown_system,
+                send_player_input,
+                update_area_of_interest,
+                (
+                    handle_connection_events,
+                    leave_when_removed,
+                    retry_connection,
+                    update_reconnect_overlay,
+                )
+                    .chain(),
                 despawn_opponent_hooks,
+                setup_player.run_if(not(any_with_component::<Player>)),
+                spawn_tags.after(setup_player),
+                update_server_clock,
+                interpolate_remote_entities
+                    .after(update_server_clock)
+                    .after(update_opponent_positions)
+                    .after(render_bots_from_db),
+                interpolate_opponent_hooks
+                    .after(update_server_clock)
+                    .after(update_opponent_hooks),
+                setup_tilemap.run_if(not(resource_exists::<MapTerrain>)),
+                stream_tilemap_chunks,
             )
                 .run_if(in_game_or_edit),
         )
@@ -131,6 +157,8 @@ fn main() {
                 spawn_bots,
                 setup_blocks_player,
                 spawn_bot_blocks,
+                spawn_loose_blocks,
+                despawn_destroyed_blocks,
                 setup_blocks_opponent,
                 update_nametags_content, // update_bots,
                 update_leaderboard_from_db,
@@ -139,8 +167,13 @@ fn main() {
         )
         .insert_resource(Time::from_seconds(0.5))
         .insert_resource(SpawnedObstacles::default())
-        .insert_resource(CtxWrapper { ctx: db_setup() })
+        .insert_resource(parse::parse_server_profile())
+        .insert_resource(load_username())
+        .insert_resource(server_browser::load_server_profiles())
         .insert_resource(HookTimer(Timer::from_seconds(0.5, TimerMode::Repeating)))
         .insert_resource(SpawnedBlocks::default())
+        .insert_resource(ServerClock::default())
+        .insert_resource(AreaOfInterest::default())
+        .insert_resource(ReconnectState::default())
         .run();
 }
@@ -1,165 +1,224 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
-use image::{GenericImageView, ImageReader};
-use std::collections::HashSet;
-use crate::common::{MAP_CONFIG, LavaTiles, WaterTiles, RegTiles, StoneTiles};
-// use rand::random;
-use rand::Rng;
+use rustbourn_terrain::{Surface, Terrain};
+use std::collections::HashMap;
+use crate::common::{CtxWrapper, GameState, MapTerrain, TilemapChunks, MAP_CONFIG};
+use crate::module_bindings::{MapSource, WorldMapTableAccess};
+use crate::reconnect::ReconnectState;
+use spacetimedb_sdk::DbContext;
 
 // Define the Obstacle component
 #[derive(Component)]
 pub struct Obstacle;
 
+/// Marks the tilemap entity of a chunk, "0" is the chunk coordinate.
+#[derive(Component)]
+pub struct TilemapChunk(pub IVec2);
+
+/// Builds the map terrain from the map the server publishes in the "world_map" table, so it always
+/// matches the terrain the server checks against. Runs until the terrain exists, the map is only
+/// known once the table has been received. A map the client cannot load leaves the game.
 pub fn setup_tilemap(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    mut image: ResMut<Assets<Image>>,
-
+    ctx_wrapper: Res<CtxWrapper>,
+    mut reconnect_state: ResMut<ReconnectState>,
+    mut game_state: ResMut<NextState<GameState>>,
 ) {
+    // Not received yet, try again next frame
+    let Some(world_map) = ctx_wrapper.ctx.db.world_map().id().find(&0) else {
+        return;
+    };
+    let terrain = match load_server_map(world_map.source) {
+        Ok(terrain) => terrain,
+        Err(err) => {
+            eprintln!("Cannot load the server's map: {}", err);
+            let _ = ctx_wrapper.ctx.disconnect();
+            *reconnect_state = ReconnectState::Offline;
+            game_state.set(GameState::Menu);
+            return;
+        }
+    };
 
     // Tile images. ORDER IS IMPORTANT!
-    let texture_handle: Vec<Handle<Image>> = MAP_CONFIG
+    let textures: Vec<Handle<Image>> = MAP_CONFIG
         .tile_textures
         .iter()
-        .map(|path| {
-            let handle = asset_server.load(*path);
-            handle
-        })
+        .map(|path| asset_server.load(*path))
         .collect();
 
-    // New map with 64x64 chunks being 32x32 tiles
-    let grid_size = MAP_CONFIG.tile_size.into(); // Grid size == tile size
-    let map_type = TilemapType::default();
-    let mut tile_storage = TileStorage::empty(MAP_CONFIG.map_size);
-    let tilemap_entity = commands.spawn_empty().id();
+    // Only the terrain is built here, tiles are spawned in chunks around the camera
+    commands.insert_resource(MapTerrain { terrain });
+    commands.insert_resource(TilemapChunks {
+        loaded: HashMap::new(),
+        textures,
+    });
+}
 
-    // Load the image
-    let img = ImageReader::open(MAP_CONFIG.image_path)
-        .expect("Failed to open image")
-        .decode()
-        .expect("Failed to decode image");
+/// Checks that a map name only uses letters, digits, "_" and "-".
+fn is_valid_map_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
 
-    let (width, height) = img.dimensions();
+/// Builds the terrain of a map published by the server. The server's data is not trusted,
+/// an image name becomes a file path so it must not reach outside the assets.
+fn load_server_map(source: MapSource) -> Result<Terrain, String> {
+    match source {
+        MapSource::Image(name) if !is_valid_map_name(&name) => {
+            Err(format!("Invalid map name: {:?}", name))
+        }
+        MapSource::Image(name) => load_map_image(&format!("assets/{}.png", name)),
+        MapSource::Noise(map) => Ok(Terrain::generate(map.seed, map.width, map.height)),
+    }
+}
 
-    let mut lava_tiles = HashSet::new();
-    let mut water_tiles = HashSet::new();
-    let mut reg_tiles = HashSet::new();
-    let mut stone_tiles = HashSet::new();
+/// Loads a map image, classified with the same rules as the server.
+fn load_map_image(path: &str) -> Result<Terrain, String> {
+    let bytes =
+        std::fs::read(path).map_err(|err| format!("Failed to open image {}: {}", path, err))?;
+    Ok(Terrain::decode(&bytes))
+}
 
-    let (width, height) = img.dimensions();
+/// Spawns the chunks covering the camera view (plus a margin) and despawns the ones that left it.
+pub fn stream_tilemap_chunks(
+    mut commands: Commands,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera2d>>,
+    map_terrain: Option<Res<MapTerrain>>,
+    chunks: Option<ResMut<TilemapChunks>>,
+) {
+    let (Some(map_terrain), Some(mut chunks)) = (map_terrain, chunks) else {
+        return;
+    };
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+
+    let chunk_world_size = MAP_CONFIG.chunk_size as f32 * MAP_CONFIG.tile_size.x;
+    let map_corner = map_corner(&map_terrain.terrain);
+    let camera_pos = camera_transform.translation.truncate();
+
+    // Chunk coordinates of the visible area
+    let to_chunk = |world: Vec2| ((world - map_corner) / chunk_world_size).floor().as_ivec2();
+    let margin = IVec2::splat(MAP_CONFIG.chunk_margin);
+    let min = to_chunk(camera_pos + projection.area.min) - margin;
+    let max = to_chunk(camera_pos + projection.area.max) + margin;
+
+    // Despawn chunks that are no longer in view
+    chunks.loaded.retain(|chunk, entity| {
+        let in_view = chunk.cmpge(min).all() && chunk.cmple(max).all();
+        if !in_view {
+            commands.entity(*entity).despawn_recursive();
+        }
+        in_view
+    });
 
-    for y in 0..height {
-        for x in 0..width {
-            let tile_pos = TilePos { x, y };
-            let pixel = img.get_pixel(x, y);
-
-            let r = pixel[0];
-            let g = pixel[1];
-            let b = pixel[2];
-
-            // let mut start;
-            // let mut stop;
-
-            // let texture_index = if g == 255 {
-            //     0 // Green -> Grass
-            // } else if b == 255 {
-            //     1 // Blue -> Water
-            // } else if r == 255 {
-            //     2 // Red -> Stone
-            // } else {
-            //     3 // Default -> Dirt
-            // };
-
-            let mut rng = rand::thread_rng();
-
-            // GRASS
-            let texture_index = if r <= 20 && g >= 230 && b <= 20 {
-                reg_tiles.insert((x,y));
-                rng.gen_range(0..20)
-            // WATER
-            } else if r <= 20 && g <= 20 && b >= 230  {
-                water_tiles.insert((x, y));
-                rng.gen_range(20..29)
-            // STONE
-            } else if r <= 20 && g <= 20 && b <= 20  {
-                stone_tiles.insert((x,y));
-                rng.gen_range(29..38)
-            // DIRT
-            } else if r >= 230 && g >= 230 && b >= 230  {
-                reg_tiles.insert((x,y));
-                rng.gen_range(38..48)
-            // LAVA 
-            } else if r >= 230 && g <= 20 && b <= 20  {
-                lava_tiles.insert((x,y));
-                rng.gen_range(48..58)
-            // WATER-GRASS
-            } else if r <= 20 && g >= 230 && b >= 230  {
-                water_tiles.insert((x,y));
-                rng.gen_range(58..62)
-            // WATER-STONE
-            } else if r >= 230 && g <= 20 && b >= 230 {
-                water_tiles.insert((x,y));
-                rng.gen_range(62..67)
-            // DIRT-GRASS
-            } else if r >= 230 && g >= 230 && b <= 20  {
-                reg_tiles.insert((x,y));
-                rng.gen_range(67..77)
-            // DIRT-STONE
-            } else if r <= 200 && r >= 100 && g <= 200 && g >= 100 && b <= 200 && b >= 100  {
-                reg_tiles.insert((x,y));
-                rng.gen_range(77..81)
-            // STONE-GRASS
-            } else if r <= 70 && r >= 30 && g <= 70 && g >= 30 && b <= 70 && b >= 30  {
-                reg_tiles.insert((x,y));
-                rng.gen_range(81..86)
-            } else {
-                lava_tiles.insert((x,y));
-                49
-            };
+    // Spawn missing chunks inside the map
+    let (width, height) = map_terrain.terrain.dimensions();
+    let chunk_count = IVec2::new(
+        width.div_ceil(MAP_CONFIG.chunk_size) as i32,
+        height.div_ceil(MAP_CONFIG.chunk_size) as i32,
+    );
+    for y in min.y.max(0)..=max.y.min(chunk_count.y - 1) {
+        for x in min.x.max(0)..=max.x.min(chunk_count.x - 1) {
+            let chunk = IVec2::new(x, y);
+            if chunks.loaded.contains_key(&chunk) {
+                continue;
+            }
+            let entity = spawn_chunk(&mut commands, &map_terrain.terrain, &chunks.textures, chunk);
+            chunks.loaded.insert(chunk, entity);
+        }
+    }
+}
 
+/// Spawns the tilemap of one chunk and its tiles.
+fn spawn_chunk(
+    commands: &mut Commands,
+    terrain: &Terrain,
+    textures: &[Handle<Image>],
+    chunk: IVec2,
+) -> Entity {
+    let chunk_size = TilemapSize {
+        x: MAP_CONFIG.chunk_size,
+        y: MAP_CONFIG.chunk_size,
+    };
+    let grid_size = MAP_CONFIG.tile_size.into(); // Grid size == tile size
+    let mut tile_storage = TileStorage::empty(chunk_size);
+    let tilemap_entity = commands.spawn_empty().id();
 
+    for y in 0..chunk_size.y {
+        for x in 0..chunk_size.x {
+            let map_x = chunk.x * chunk_size.x as i32 + x as i32;
+            let map_y = chunk.y * chunk_size.y as i32 + y as i32;
+            // Chunks on the map edge may be partly outside the map
+            let Some(surface) = terrain.surface(map_x, map_y) else {
+                continue;
+            };
+
+            let tile_pos = TilePos { x, y };
             let tile_entity = commands
                 .spawn(TileBundle {
                     position: tile_pos,
                     tilemap_id: TilemapId(tilemap_entity),
-                    texture_index: TileTextureIndex(texture_index),
+                    texture_index: TileTextureIndex(texture_index(surface, map_x, map_y)),
                     ..Default::default()
                 })
                 .id();
-
+            commands.entity(tilemap_entity).add_child(tile_entity);
             tile_storage.set(&tile_pos, tile_entity);
         }
     }
 
-    // Insert tilemap entity
-    commands.entity(tilemap_entity).insert(TilemapBundle {
-        grid_size,
-        map_type,
-        size: MAP_CONFIG.map_size,
-        storage: tile_storage,
-        texture: TilemapTexture::Vector(texture_handle),
-        tile_size: MAP_CONFIG.tile_size,
-        transform: get_tilemap_center_transform(&MAP_CONFIG.map_size, &grid_size, &map_type, 0.0),
-        ..Default::default()
-    });
-
-    // Insert lava tile data as a resource
-    commands.insert_resource(LavaTiles {
-        positions: lava_tiles,
-    });
-
-    // Insert water tile data as a resource
-    commands.insert_resource(WaterTiles {
-        positions: water_tiles,
-    });
+    // Tiles are centered on their position, same as the full map centered on the origin
+    let chunk_corner = map_corner(terrain)
+        + chunk.as_vec2() * MAP_CONFIG.chunk_size as f32 * MAP_CONFIG.tile_size.x;
+    let tile_center = Vec2::new(MAP_CONFIG.tile_size.x, MAP_CONFIG.tile_size.y) / 2.0;
+
+    commands.entity(tilemap_entity).insert((
+        TilemapBundle {
+            grid_size,
+            map_type: TilemapType::default(),
+            size: chunk_size,
+            storage: tile_storage,
+            texture: TilemapTexture::Vector(textures.to_vec()),
+            tile_size: MAP_CONFIG.tile_size,
+            transform: Transform::from_translation((chunk_corner + tile_center).extend(0.0)),
+            ..Default::default()
+        },
+        TilemapChunk(chunk),
+    ));
+
+    tilemap_entity
+}
 
-    // Insert grass tile data as a resource
-    commands.insert_resource(RegTiles {
-        positions: reg_tiles,
-    });
+/// World position of the bottom left corner of the map, the map is centered on the origin.
+fn map_corner(terrain: &Terrain) -> Vec2 {
+    let (width, height) = terrain.dimensions();
+    -Vec2::new(
+        width as f32 * MAP_CONFIG.tile_size.x,
+        height as f32 * MAP_CONFIG.tile_size.y,
+    ) / 2.0
+}
 
-    // Insert stone tile data as a resource
-    commands.insert_resource(StoneTiles {
-        positions: stone_tiles,
-    });
+/// Texture of a tile. The variation is picked from the tile position,
+/// so a chunk looks the same every time it is spawned.
+fn texture_index(surface: Surface, x: i32, y: i32) -> u32 {
+    // Texture ranges follow the order of MAP_CONFIG.tile_textures
+    let range = match surface {
+        Surface::Grass => 0..20,
+        Surface::Water => 20..29,
+        Surface::Stone => 29..38,
+        Surface::Dirt => 38..48,
+        Surface::Lava => 48..58,
+        Surface::WaterGrass => 58..62,
+        Surface::WaterStone => 62..67,
+        Surface::DirtGrass => 67..77,
+        Surface::DirtStone => 77..81,
+        Surface::StoneGrass => 81..86,
+        Surface::Unknown => 49..50,
+    };
+    let variation = (x as u32).wrapping_mul(73_856_093) ^ (y as u32).wrapping_mul(19_349_663);
+    range.start + variation % (range.end - range.start)
 }
@@ -4,6 +4,7 @@
 #![allow(unused, clippy::all)]
 use super::block_type::Block;
 use super::owner_type_type::OwnerType;
+use super::vec_2_type::Vec2;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 /// Table handle for the table `block`.
@@ -5,6 +5,7 @@
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 use super::owner_type_type::OwnerType;
+use super::vec_2_type::Vec2;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
@@ -13,6 +14,9 @@ pub struct Block {
     pub offset_y: i32,
     pub id: u64,
     pub owner: OwnerType,
+    pub position: Vec2,
+    pub chunk_x: i32,
+    pub chunk_y: i32,
 }
 
 impl __sdk::InModule for Block {
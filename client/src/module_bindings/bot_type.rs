@@ -5,6 +5,7 @@
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 use super::bevy_transform_type::BevyTransform;
+use super::bot_state_type::BotState;
 use super::vec_3_type::Vec3;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
@@ -15,6 +16,12 @@ pub struct Bot {
     pub alive: bool,
     pub movement_dir: Vec3,
     pub rotation_dir: f32,
+    pub state: BotState,
+    pub last_hook: __sdk::Timestamp,
+    pub died_at: __sdk::Timestamp,
+    pub updated_at: __sdk::Timestamp,
+    pub chunk_x: i32,
+    pub chunk_y: i32,
 }
 
 impl __sdk::InModule for Bot {
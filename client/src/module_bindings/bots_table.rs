@@ -3,6 +3,7 @@
 
 #![allow(unused, clippy::all)]
 use super::bevy_transform_type::BevyTransform;
+use super::bot_state_type::BotState;
 use super::bot_type::Bot;
 use super::vec_3_type::Vec3;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
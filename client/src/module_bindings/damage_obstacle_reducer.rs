@@ -8,15 +8,11 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[sats(crate = __lib)]
 pub(super) struct DamageObstacleArgs {
     pub id: u64,
-    pub damage: u32,
 }
 
 impl From<DamageObstacleArgs> for super::Reducer {
     fn from(args: DamageObstacleArgs) -> Self {
-        Self::DamageObstacle {
-            id: args.id,
-            damage: args.damage,
-        }
+        Self::DamageObstacle { id: args.id }
     }
 }
 
@@ -36,7 +32,7 @@ pub trait damage_obstacle {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_damage_obstacle`] callbacks.
-    fn damage_obstacle(&self, id: u64, damage: u32) -> __sdk::Result<()>;
+    fn damage_obstacle(&self, id: u64) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `damage_obstacle`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -46,7 +42,7 @@ pub trait damage_obstacle {
     /// to cancel the callback.
     fn on_damage_obstacle(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &u64, &u32) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &u64) + Send + 'static,
     ) -> DamageObstacleCallbackId;
     /// Cancel a callback previously registered by [`Self::on_damage_obstacle`],
     /// causing it not to run in the future.
@@ -54,13 +50,13 @@ pub trait damage_obstacle {
 }
 
 impl damage_obstacle for super::RemoteReducers {
-    fn damage_obstacle(&self, id: u64, damage: u32) -> __sdk::Result<()> {
+    fn damage_obstacle(&self, id: u64) -> __sdk::Result<()> {
         self.imp
-            .call_reducer("damage_obstacle", DamageObstacleArgs { id, damage })
+            .call_reducer("damage_obstacle", DamageObstacleArgs { id })
     }
     fn on_damage_obstacle(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &u64, &u32) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &u64) + Send + 'static,
     ) -> DamageObstacleCallbackId {
         DamageObstacleCallbackId(self.imp.on_reducer(
             "damage_obstacle",
@@ -68,7 +64,7 @@ impl damage_obstacle for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::DamageObstacle { id, damage },
+                            reducer: super::Reducer::DamageObstacle { id },
                             ..
                         },
                     ..
@@ -76,7 +72,7 @@ impl damage_obstacle for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, id, damage)
+                callback(ctx, id)
             }),
         ))
     }
@@ -1,102 +0,0 @@
-// THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
-// WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
-
-#![allow(unused, clippy::all)]
-use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
-
-#[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
-#[sats(crate = __lib)]
-pub(super) struct GenerateLeaderboardArgs {}
-
-impl From<GenerateLeaderboardArgs> for super::Reducer {
-    fn from(args: GenerateLeaderboardArgs) -> Self {
-        Self::GenerateLeaderboard
-    }
-}
-
-impl __sdk::InModule for GenerateLeaderboardArgs {
-    type Module = super::RemoteModule;
-}
-
-pub struct GenerateLeaderboardCallbackId(__sdk::CallbackId);
-
-#[allow(non_camel_case_types)]
-/// Extension trait for access to the reducer `generate_leaderboard`.
-///
-/// Implemented for [`super::RemoteReducers`].
-pub trait generate_leaderboard {
-    /// Request that the remote module invoke the reducer `generate_leaderboard` to run as soon as possible.
-    ///
-    /// This method returns immediately, and errors only if we are unable to send the request.
-    /// The reducer will run asynchronously in the future,
-    ///  and its status can be observed by listening for [`Self::on_generate_leaderboard`] callbacks.
-    fn generate_leaderboard(&self) -> __sdk::Result<()>;
-    /// Register a callback to run whenever we are notified of an invocation of the reducer `generate_leaderboard`.
-    ///
-    /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
-    /// to determine the reducer's status.
-    ///
-    /// The returned [`GenerateLeaderboardCallbackId`] can be passed to [`Self::remove_on_generate_leaderboard`]
-    /// to cancel the callback.
-    fn on_generate_leaderboard(
-        &self,
-        callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
-    ) -> GenerateLeaderboardCallbackId;
-    /// Cancel a callback previously registered by [`Self::on_generate_leaderboard`],
-    /// causing it not to run in the future.
-    fn remove_on_generate_leaderboard(&self, callback: GenerateLeaderboardCallbackId);
-}
-
-impl generate_leaderboard for super::RemoteReducers {
-    fn generate_leaderboard(&self) -> __sdk::Result<()> {
-        self.imp
-            .call_reducer("generate_leaderboard", GenerateLeaderboardArgs {})
-    }
-    fn on_generate_leaderboard(
-        &self,
-        mut callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
-    ) -> GenerateLeaderboardCallbackId {
-        GenerateLeaderboardCallbackId(self.imp.on_reducer(
-            "generate_leaderboard",
-            Box::new(move |ctx: &super::ReducerEventContext| {
-                let super::ReducerEventContext {
-                    event:
-                        __sdk::ReducerEvent {
-                            reducer: super::Reducer::GenerateLeaderboard {},
-                            ..
-                        },
-                    ..
-                } = ctx
-                else {
-                    unreachable!()
-                };
-                callback(ctx)
-            }),
-        ))
-    }
-    fn remove_on_generate_leaderboard(&self, callback: GenerateLeaderboardCallbackId) {
-        self.imp
-            .remove_on_reducer("generate_leaderboard", callback.0)
-    }
-}
-
-#[allow(non_camel_case_types)]
-#[doc(hidden)]
-/// Extension trait for setting the call-flags for the reducer `generate_leaderboard`.
-///
-/// Implemented for [`super::SetReducerFlags`].
-///
-/// This type is currently unstable and may be removed without a major version bump.
-pub trait set_flags_for_generate_leaderboard {
-    /// Set the call-reducer flags for the reducer `generate_leaderboard` to `flags`.
-    ///
-    /// This type is currently unstable and may be removed without a major version bump.
-    fn generate_leaderboard(&self, flags: __ws::CallReducerFlags);
-}
-
-impl set_flags_for_generate_leaderboard for super::SetReducerFlags {
-    fn generate_leaderboard(&self, flags: __ws::CallReducerFlags) {
-        self.imp
-            .set_call_reducer_flags("generate_leaderboard", flags);
-    }
-}
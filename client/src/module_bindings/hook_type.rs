@@ -13,6 +13,9 @@ pub struct Hook {
     pub rotation: f32,
     pub width: f32,
     pub height: f32,
+    pub charge_secs: f32,
+    pub target_length: f32,
+    pub last_fire: __sdk::Timestamp,
 }
 
 impl __sdk::InModule for Hook {
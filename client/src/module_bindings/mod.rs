@@ -4,56 +4,118 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod admin_table;
+pub mod admin_type;
+pub mod ban_player_reducer;
+pub mod ban_table;
+pub mod ban_type;
 pub mod bevy_transform_type;
 pub mod block_table;
 pub mod block_type;
+pub mod bot_state_type;
+pub mod bot_tick_reducer;
+pub mod bot_tick_schedule_table;
+pub mod bot_tick_schedule_type;
 pub mod bot_type;
 pub mod bots_table;
+pub mod configure_world_reducer;
 pub mod damage_obstacle_reducer;
-pub mod decrease_grid_load_reducer;
-pub mod generate_leaderboard_reducer;
+pub mod erupt_volcanoes_reducer;
+pub mod grant_admin_reducer;
+pub mod grid_cell_table;
+pub mod grid_cell_type;
 pub mod grid_type;
 pub mod hook_type;
+pub mod kick_player_reducer;
+pub mod lava_exposure_table;
+pub mod lava_exposure_type;
+pub mod lava_tick_reducer;
+pub mod lava_tick_schedule_table;
+pub mod lava_tick_schedule_type;
 pub mod leaderboard_table;
 pub mod leaderboard_type;
+pub mod map_source_type;
+pub mod noise_map_type;
+pub mod obstacle_kind_type;
+pub mod obstacle_respawn_schedule_table;
+pub mod obstacle_respawn_schedule_type;
+pub mod obstacle_shape_type;
 pub mod obstacle_table;
 pub mod obstacle_type;
 pub mod owner_type_type;
 pub mod player_connected_reducer;
 pub mod player_disconnected_reducer;
+pub mod player_input_table;
+pub mod player_input_type;
 pub mod player_table;
+pub mod player_tick_reducer;
+pub mod player_tick_schedule_table;
+pub mod player_tick_schedule_type;
 pub mod player_type;
+pub mod rename_player_reducer;
+pub mod reset_world_reducer;
+pub mod respawn_obstacle_reducer;
+pub mod revoke_admin_reducer;
+pub mod send_input_reducer;
 pub mod set_name_reducer;
+pub mod steal_block_reducer;
 pub mod track_table;
 pub mod track_type;
+pub mod unban_player_reducer;
 pub mod update_block_owner_reducer;
-pub mod update_bot_position_reducer;
-pub mod update_hook_movement_reducer;
-pub mod update_hook_position_reducer;
-pub mod update_owner_grid_reducer;
-pub mod update_player_position_reducer;
 pub mod update_tracks_system_reducer;
 pub mod vec_2_type;
 pub mod vec_3_type;
-
+pub mod volcano_eruption_schedule_table;
+pub mod volcano_eruption_schedule_type;
+pub mod world_config_table;
+pub mod world_config_type;
+pub mod world_map_table;
+pub mod world_map_type;
+pub mod world_settings_type;
+
+pub use admin_table::*;
+pub use admin_type::Admin;
+pub use ban_player_reducer::{ban_player, set_flags_for_ban_player, BanPlayerCallbackId};
+pub use ban_table::*;
+pub use ban_type::Ban;
 pub use bevy_transform_type::BevyTransform;
 pub use block_table::*;
 pub use block_type::Block;
+pub use bot_state_type::BotState;
+pub use bot_tick_reducer::{bot_tick, set_flags_for_bot_tick, BotTickCallbackId};
+pub use bot_tick_schedule_table::*;
+pub use bot_tick_schedule_type::BotTickSchedule;
 pub use bot_type::Bot;
 pub use bots_table::*;
+pub use configure_world_reducer::{
+    configure_world, set_flags_for_configure_world, ConfigureWorldCallbackId,
+};
 pub use damage_obstacle_reducer::{
     damage_obstacle, set_flags_for_damage_obstacle, DamageObstacleCallbackId,
 };
-pub use decrease_grid_load_reducer::{
-    decrease_grid_load, set_flags_for_decrease_grid_load, DecreaseGridLoadCallbackId,
-};
-pub use generate_leaderboard_reducer::{
-    generate_leaderboard, set_flags_for_generate_leaderboard, GenerateLeaderboardCallbackId,
+pub use erupt_volcanoes_reducer::{
+    erupt_volcanoes, set_flags_for_erupt_volcanoes, EruptVolcanoesCallbackId,
 };
+pub use grant_admin_reducer::{grant_admin, set_flags_for_grant_admin, GrantAdminCallbackId};
+pub use grid_cell_table::*;
+pub use grid_cell_type::GridCell;
 pub use grid_type::Grid;
 pub use hook_type::Hook;
+pub use kick_player_reducer::{kick_player, set_flags_for_kick_player, KickPlayerCallbackId};
+pub use lava_exposure_table::*;
+pub use lava_exposure_type::LavaExposure;
+pub use lava_tick_reducer::{lava_tick, set_flags_for_lava_tick, LavaTickCallbackId};
+pub use lava_tick_schedule_table::*;
+pub use lava_tick_schedule_type::LavaTickSchedule;
 pub use leaderboard_table::*;
 pub use leaderboard_type::Leaderboard;
+pub use map_source_type::MapSource;
+pub use noise_map_type::NoiseMap;
+pub use obstacle_kind_type::ObstacleKind;
+pub use obstacle_respawn_schedule_table::*;
+pub use obstacle_respawn_schedule_type::ObstacleRespawnSchedule;
+pub use obstacle_shape_type::ObstacleShape;
 pub use obstacle_table::*;
 pub use obstacle_type::Obstacle;
 pub use owner_type_type::OwnerType;
@@ -63,34 +125,42 @@ pub use player_connected_reducer::{
 pub use player_disconnected_reducer::{
     player_disconnected, set_flags_for_player_disconnected, PlayerDisconnectedCallbackId,
 };
+pub use player_input_table::*;
+pub use player_input_type::PlayerInput;
 pub use player_table::*;
+pub use player_tick_reducer::{player_tick, set_flags_for_player_tick, PlayerTickCallbackId};
+pub use player_tick_schedule_table::*;
+pub use player_tick_schedule_type::PlayerTickSchedule;
 pub use player_type::Player;
+pub use rename_player_reducer::{
+    rename_player, set_flags_for_rename_player, RenamePlayerCallbackId,
+};
+pub use reset_world_reducer::{reset_world, set_flags_for_reset_world, ResetWorldCallbackId};
+pub use respawn_obstacle_reducer::{
+    respawn_obstacle, set_flags_for_respawn_obstacle, RespawnObstacleCallbackId,
+};
+pub use revoke_admin_reducer::{revoke_admin, set_flags_for_revoke_admin, RevokeAdminCallbackId};
+pub use send_input_reducer::{send_input, set_flags_for_send_input, SendInputCallbackId};
 pub use set_name_reducer::{set_flags_for_set_name, set_name, SetNameCallbackId};
+pub use steal_block_reducer::{set_flags_for_steal_block, steal_block, StealBlockCallbackId};
 pub use track_table::*;
 pub use track_type::Track;
+pub use unban_player_reducer::{set_flags_for_unban_player, unban_player, UnbanPlayerCallbackId};
 pub use update_block_owner_reducer::{
     set_flags_for_update_block_owner, update_block_owner, UpdateBlockOwnerCallbackId,
 };
-pub use update_bot_position_reducer::{
-    set_flags_for_update_bot_position, update_bot_position, UpdateBotPositionCallbackId,
-};
-pub use update_hook_movement_reducer::{
-    set_flags_for_update_hook_movement, update_hook_movement, UpdateHookMovementCallbackId,
-};
-pub use update_hook_position_reducer::{
-    set_flags_for_update_hook_position, update_hook_position, UpdateHookPositionCallbackId,
-};
-pub use update_owner_grid_reducer::{
-    set_flags_for_update_owner_grid, update_owner_grid, UpdateOwnerGridCallbackId,
-};
-pub use update_player_position_reducer::{
-    set_flags_for_update_player_position, update_player_position, UpdatePlayerPositionCallbackId,
-};
 pub use update_tracks_system_reducer::{
     set_flags_for_update_tracks_system, update_tracks_system, UpdateTracksSystemCallbackId,
 };
 pub use vec_2_type::Vec2;
 pub use vec_3_type::Vec3;
+pub use volcano_eruption_schedule_table::*;
+pub use volcano_eruption_schedule_type::VolcanoEruptionSchedule;
+pub use world_config_table::*;
+pub use world_config_type::WorldConfig;
+pub use world_map_table::*;
+pub use world_map_type::WorldMap;
+pub use world_settings_type::WorldSettings;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -100,49 +170,68 @@ pub use vec_3_type::Vec3;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    BanPlayer {
+        identity: __sdk::Identity,
+        reason: String,
+    },
+    BotTick {
+        _arg: BotTickSchedule,
+    },
+    ConfigureWorld {
+        settings: WorldSettings,
+    },
     DamageObstacle {
         id: u64,
-        damage: u32,
     },
-    DecreaseGridLoad {
+    EruptVolcanoes {
+        _arg: VolcanoEruptionSchedule,
+    },
+    GrantAdmin {
+        identity: __sdk::Identity,
+    },
+    KickPlayer {
         identity: __sdk::Identity,
-        load: i32,
+        reason: String,
+    },
+    LavaTick {
+        _arg: LavaTickSchedule,
     },
-    GenerateLeaderboard,
     PlayerConnected,
     PlayerDisconnected,
+    PlayerTick {
+        _arg: PlayerTickSchedule,
+    },
+    RenamePlayer {
+        identity: __sdk::Identity,
+        name: String,
+    },
+    ResetWorld,
+    RespawnObstacle {
+        arg: ObstacleRespawnSchedule,
+    },
+    RevokeAdmin {
+        identity: __sdk::Identity,
+    },
+    SendInput {
+        throttle: f32,
+        turn: f32,
+        hook_charge: bool,
+    },
     SetName {
         name: String,
     },
+    StealBlock {
+        block_id: u64,
+    },
+    UnbanPlayer {
+        identity: __sdk::Identity,
+    },
     UpdateBlockOwner {
         block_id: u64,
         new_owner: OwnerType,
         offset_x: i32,
         offset_y: i32,
     },
-    UpdateBotPosition {
-        bevy_transform: BevyTransform,
-        bot_id: u64,
-        new_rotate_dir: f32,
-    },
-    UpdateHookMovement {
-        identity: __sdk::Identity,
-        width: f32,
-        height: f32,
-    },
-    UpdateHookPosition {
-        identity: __sdk::Identity,
-        position: Vec2,
-        rotation: f32,
-    },
-    UpdateOwnerGrid {
-        load: i32,
-        next_free_x: i32,
-        next_free_y: i32,
-    },
-    UpdatePlayerPosition {
-        bevy_transform: BevyTransform,
-    },
     UpdateTracksSystem {
         owner_identity: __sdk::Identity,
         position: BevyTransform,
@@ -160,18 +249,26 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::BanPlayer { .. } => "ban_player",
+            Reducer::BotTick { .. } => "bot_tick",
+            Reducer::ConfigureWorld { .. } => "configure_world",
             Reducer::DamageObstacle { .. } => "damage_obstacle",
-            Reducer::DecreaseGridLoad { .. } => "decrease_grid_load",
-            Reducer::GenerateLeaderboard => "generate_leaderboard",
+            Reducer::EruptVolcanoes { .. } => "erupt_volcanoes",
+            Reducer::GrantAdmin { .. } => "grant_admin",
+            Reducer::KickPlayer { .. } => "kick_player",
+            Reducer::LavaTick { .. } => "lava_tick",
             Reducer::PlayerConnected => "player_connected",
             Reducer::PlayerDisconnected => "player_disconnected",
+            Reducer::PlayerTick { .. } => "player_tick",
+            Reducer::RenamePlayer { .. } => "rename_player",
+            Reducer::ResetWorld => "reset_world",
+            Reducer::RespawnObstacle { .. } => "respawn_obstacle",
+            Reducer::RevokeAdmin { .. } => "revoke_admin",
+            Reducer::SendInput { .. } => "send_input",
             Reducer::SetName { .. } => "set_name",
+            Reducer::StealBlock { .. } => "steal_block",
+            Reducer::UnbanPlayer { .. } => "unban_player",
             Reducer::UpdateBlockOwner { .. } => "update_block_owner",
-            Reducer::UpdateBotPosition { .. } => "update_bot_position",
-            Reducer::UpdateHookMovement { .. } => "update_hook_movement",
-            Reducer::UpdateHookPosition { .. } => "update_hook_position",
-            Reducer::UpdateOwnerGrid { .. } => "update_owner_grid",
-            Reducer::UpdatePlayerPosition { .. } => "update_player_position",
             Reducer::UpdateTracksSystem { .. } => "update_tracks_system",
         }
     }
@@ -180,18 +277,51 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "ban_player" => Ok(
+                __sdk::parse_reducer_args::<ban_player_reducer::BanPlayerArgs>(
+                    "ban_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "bot_tick" => Ok(__sdk::parse_reducer_args::<bot_tick_reducer::BotTickArgs>(
+                "bot_tick",
+                &value.args,
+            )?
+            .into()),
+            "configure_world" => Ok(__sdk::parse_reducer_args::<
+                configure_world_reducer::ConfigureWorldArgs,
+            >("configure_world", &value.args)?
+            .into()),
             "damage_obstacle" => Ok(__sdk::parse_reducer_args::<
                 damage_obstacle_reducer::DamageObstacleArgs,
             >("damage_obstacle", &value.args)?
             .into()),
-            "decrease_grid_load" => Ok(__sdk::parse_reducer_args::<
-                decrease_grid_load_reducer::DecreaseGridLoadArgs,
-            >("decrease_grid_load", &value.args)?
-            .into()),
-            "generate_leaderboard" => Ok(__sdk::parse_reducer_args::<
-                generate_leaderboard_reducer::GenerateLeaderboardArgs,
-            >("generate_leaderboard", &value.args)?
+            "erupt_volcanoes" => Ok(__sdk::parse_reducer_args::<
+                erupt_volcanoes_reducer::EruptVolcanoesArgs,
+            >("erupt_volcanoes", &value.args)?
             .into()),
+            "grant_admin" => Ok(
+                __sdk::parse_reducer_args::<grant_admin_reducer::GrantAdminArgs>(
+                    "grant_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "kick_player" => Ok(
+                __sdk::parse_reducer_args::<kick_player_reducer::KickPlayerArgs>(
+                    "kick_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "lava_tick" => Ok(
+                __sdk::parse_reducer_args::<lava_tick_reducer::LavaTickArgs>(
+                    "lava_tick",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "player_connected" => Ok(__sdk::parse_reducer_args::<
                 player_connected_reducer::PlayerConnectedArgs,
             >("player_connected", &value.args)?
@@ -200,35 +330,65 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 player_disconnected_reducer::PlayerDisconnectedArgs,
             >("player_disconnected", &value.args)?
             .into()),
+            "player_tick" => Ok(
+                __sdk::parse_reducer_args::<player_tick_reducer::PlayerTickArgs>(
+                    "player_tick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "rename_player" => Ok(__sdk::parse_reducer_args::<
+                rename_player_reducer::RenamePlayerArgs,
+            >("rename_player", &value.args)?
+            .into()),
+            "reset_world" => Ok(
+                __sdk::parse_reducer_args::<reset_world_reducer::ResetWorldArgs>(
+                    "reset_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "respawn_obstacle" => Ok(__sdk::parse_reducer_args::<
+                respawn_obstacle_reducer::RespawnObstacleArgs,
+            >("respawn_obstacle", &value.args)?
+            .into()),
+            "revoke_admin" => Ok(
+                __sdk::parse_reducer_args::<revoke_admin_reducer::RevokeAdminArgs>(
+                    "revoke_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "send_input" => Ok(
+                __sdk::parse_reducer_args::<send_input_reducer::SendInputArgs>(
+                    "send_input",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "set_name" => Ok(__sdk::parse_reducer_args::<set_name_reducer::SetNameArgs>(
                 "set_name",
                 &value.args,
             )?
             .into()),
+            "steal_block" => Ok(
+                __sdk::parse_reducer_args::<steal_block_reducer::StealBlockArgs>(
+                    "steal_block",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "unban_player" => Ok(
+                __sdk::parse_reducer_args::<unban_player_reducer::UnbanPlayerArgs>(
+                    "unban_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "update_block_owner" => Ok(__sdk::parse_reducer_args::<
                 update_block_owner_reducer::UpdateBlockOwnerArgs,
             >("update_block_owner", &value.args)?
             .into()),
-            "update_bot_position" => Ok(__sdk::parse_reducer_args::<
-                update_bot_position_reducer::UpdateBotPositionArgs,
-            >("update_bot_position", &value.args)?
-            .into()),
-            "update_hook_movement" => Ok(__sdk::parse_reducer_args::<
-                update_hook_movement_reducer::UpdateHookMovementArgs,
-            >("update_hook_movement", &value.args)?
-            .into()),
-            "update_hook_position" => Ok(__sdk::parse_reducer_args::<
-                update_hook_position_reducer::UpdateHookPositionArgs,
-            >("update_hook_position", &value.args)?
-            .into()),
-            "update_owner_grid" => Ok(__sdk::parse_reducer_args::<
-                update_owner_grid_reducer::UpdateOwnerGridArgs,
-            >("update_owner_grid", &value.args)?
-            .into()),
-            "update_player_position" => Ok(__sdk::parse_reducer_args::<
-                update_player_position_reducer::UpdatePlayerPositionArgs,
-            >("update_player_position", &value.args)?
-            .into()),
             "update_tracks_system" => Ok(__sdk::parse_reducer_args::<
                 update_tracks_system_reducer::UpdateTracksSystemArgs,
             >("update_tracks_system", &value.args)?
@@ -247,12 +407,24 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    admin: __sdk::TableUpdate<Admin>,
+    ban: __sdk::TableUpdate<Ban>,
     block: __sdk::TableUpdate<Block>,
+    bot_tick_schedule: __sdk::TableUpdate<BotTickSchedule>,
     bots: __sdk::TableUpdate<Bot>,
+    grid_cell: __sdk::TableUpdate<GridCell>,
+    lava_exposure: __sdk::TableUpdate<LavaExposure>,
+    lava_tick_schedule: __sdk::TableUpdate<LavaTickSchedule>,
     leaderboard: __sdk::TableUpdate<Leaderboard>,
     obstacle: __sdk::TableUpdate<Obstacle>,
+    obstacle_respawn_schedule: __sdk::TableUpdate<ObstacleRespawnSchedule>,
     player: __sdk::TableUpdate<Player>,
+    player_input: __sdk::TableUpdate<PlayerInput>,
+    player_tick_schedule: __sdk::TableUpdate<PlayerTickSchedule>,
     track: __sdk::TableUpdate<Track>,
+    volcano_eruption_schedule: __sdk::TableUpdate<VolcanoEruptionSchedule>,
+    world_config: __sdk::TableUpdate<WorldConfig>,
+    world_map: __sdk::TableUpdate<WorldMap>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -261,16 +433,53 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "admin" => db_update.admin = admin_table::parse_table_update(table_update)?,
+                "ban" => db_update.ban = ban_table::parse_table_update(table_update)?,
                 "block" => db_update.block = block_table::parse_table_update(table_update)?,
+                "bot_tick_schedule" => {
+                    db_update.bot_tick_schedule =
+                        bot_tick_schedule_table::parse_table_update(table_update)?
+                }
                 "bots" => db_update.bots = bots_table::parse_table_update(table_update)?,
+                "grid_cell" => {
+                    db_update.grid_cell = grid_cell_table::parse_table_update(table_update)?
+                }
+                "lava_exposure" => {
+                    db_update.lava_exposure = lava_exposure_table::parse_table_update(table_update)?
+                }
+                "lava_tick_schedule" => {
+                    db_update.lava_tick_schedule =
+                        lava_tick_schedule_table::parse_table_update(table_update)?
+                }
                 "leaderboard" => {
                     db_update.leaderboard = leaderboard_table::parse_table_update(table_update)?
                 }
                 "obstacle" => {
                     db_update.obstacle = obstacle_table::parse_table_update(table_update)?
                 }
+                "obstacle_respawn_schedule" => {
+                    db_update.obstacle_respawn_schedule =
+                        obstacle_respawn_schedule_table::parse_table_update(table_update)?
+                }
                 "player" => db_update.player = player_table::parse_table_update(table_update)?,
+                "player_input" => {
+                    db_update.player_input = player_input_table::parse_table_update(table_update)?
+                }
+                "player_tick_schedule" => {
+                    db_update.player_tick_schedule =
+                        player_tick_schedule_table::parse_table_update(table_update)?
+                }
                 "track" => db_update.track = track_table::parse_table_update(table_update)?,
+                "volcano_eruption_schedule" => {
+                    db_update.volcano_eruption_schedule =
+                        volcano_eruption_schedule_table::parse_table_update(table_update)?
+                }
+                "world_config" => {
+                    db_update.world_config = world_config_table::parse_table_update(table_update)?
+                }
+                "world_map" => {
+                    db_update.world_map = world_map_table::parse_table_update(table_update)?
+                }
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -297,24 +506,69 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.admin = cache
+            .apply_diff_to_table::<Admin>("admin", &self.admin)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.ban = cache
+            .apply_diff_to_table::<Ban>("ban", &self.ban)
+            .with_updates_by_pk(|row| &row.identity);
         diff.block = cache
             .apply_diff_to_table::<Block>("block", &self.block)
             .with_updates_by_pk(|row| &row.id);
+        diff.bot_tick_schedule = cache
+            .apply_diff_to_table::<BotTickSchedule>("bot_tick_schedule", &self.bot_tick_schedule)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.bots = cache
             .apply_diff_to_table::<Bot>("bots", &self.bots)
             .with_updates_by_pk(|row| &row.id);
+        diff.grid_cell = cache
+            .apply_diff_to_table::<GridCell>("grid_cell", &self.grid_cell)
+            .with_updates_by_pk(|row| &row.cell);
+        diff.lava_exposure = cache
+            .apply_diff_to_table::<LavaExposure>("lava_exposure", &self.lava_exposure)
+            .with_updates_by_pk(|row| &row.block_id);
+        diff.lava_tick_schedule = cache
+            .apply_diff_to_table::<LavaTickSchedule>("lava_tick_schedule", &self.lava_tick_schedule)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.leaderboard = cache
             .apply_diff_to_table::<Leaderboard>("leaderboard", &self.leaderboard)
             .with_updates_by_pk(|row| &row.id);
         diff.obstacle = cache
             .apply_diff_to_table::<Obstacle>("obstacle", &self.obstacle)
             .with_updates_by_pk(|row| &row.id);
+        diff.obstacle_respawn_schedule = cache
+            .apply_diff_to_table::<ObstacleRespawnSchedule>(
+                "obstacle_respawn_schedule",
+                &self.obstacle_respawn_schedule,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.player = cache
             .apply_diff_to_table::<Player>("player", &self.player)
             .with_updates_by_pk(|row| &row.identity);
+        diff.player_input = cache
+            .apply_diff_to_table::<PlayerInput>("player_input", &self.player_input)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.player_tick_schedule = cache
+            .apply_diff_to_table::<PlayerTickSchedule>(
+                "player_tick_schedule",
+                &self.player_tick_schedule,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.track = cache
             .apply_diff_to_table::<Track>("track", &self.track)
             .with_updates_by_pk(|row| &row.owner_identity);
+        diff.volcano_eruption_schedule = cache
+            .apply_diff_to_table::<VolcanoEruptionSchedule>(
+                "volcano_eruption_schedule",
+                &self.volcano_eruption_schedule,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.world_config = cache
+            .apply_diff_to_table::<WorldConfig>("world_config", &self.world_config)
+            .with_updates_by_pk(|row| &row.id);
+        diff.world_map = cache
+            .apply_diff_to_table::<WorldMap>("world_map", &self.world_map)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -324,12 +578,24 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    admin: __sdk::TableAppliedDiff<'r, Admin>,
+    ban: __sdk::TableAppliedDiff<'r, Ban>,
     block: __sdk::TableAppliedDiff<'r, Block>,
+    bot_tick_schedule: __sdk::TableAppliedDiff<'r, BotTickSchedule>,
     bots: __sdk::TableAppliedDiff<'r, Bot>,
+    grid_cell: __sdk::TableAppliedDiff<'r, GridCell>,
+    lava_exposure: __sdk::TableAppliedDiff<'r, LavaExposure>,
+    lava_tick_schedule: __sdk::TableAppliedDiff<'r, LavaTickSchedule>,
     leaderboard: __sdk::TableAppliedDiff<'r, Leaderboard>,
     obstacle: __sdk::TableAppliedDiff<'r, Obstacle>,
+    obstacle_respawn_schedule: __sdk::TableAppliedDiff<'r, ObstacleRespawnSchedule>,
     player: __sdk::TableAppliedDiff<'r, Player>,
+    player_input: __sdk::TableAppliedDiff<'r, PlayerInput>,
+    player_tick_schedule: __sdk::TableAppliedDiff<'r, PlayerTickSchedule>,
     track: __sdk::TableAppliedDiff<'r, Track>,
+    volcano_eruption_schedule: __sdk::TableAppliedDiff<'r, VolcanoEruptionSchedule>,
+    world_config: __sdk::TableAppliedDiff<'r, WorldConfig>,
+    world_map: __sdk::TableAppliedDiff<'r, WorldMap>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -342,16 +608,60 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Admin>("admin", &self.admin, event);
+        callbacks.invoke_table_row_callbacks::<Ban>("ban", &self.ban, event);
         callbacks.invoke_table_row_callbacks::<Block>("block", &self.block, event);
+        callbacks.invoke_table_row_callbacks::<BotTickSchedule>(
+            "bot_tick_schedule",
+            &self.bot_tick_schedule,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Bot>("bots", &self.bots, event);
+        callbacks.invoke_table_row_callbacks::<GridCell>("grid_cell", &self.grid_cell, event);
+        callbacks.invoke_table_row_callbacks::<LavaExposure>(
+            "lava_exposure",
+            &self.lava_exposure,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LavaTickSchedule>(
+            "lava_tick_schedule",
+            &self.lava_tick_schedule,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Leaderboard>(
             "leaderboard",
             &self.leaderboard,
             event,
         );
         callbacks.invoke_table_row_callbacks::<Obstacle>("obstacle", &self.obstacle, event);
+        callbacks.invoke_table_row_callbacks::<ObstacleRespawnSchedule>(
+            "obstacle_respawn_schedule",
+            &self.obstacle_respawn_schedule,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Player>("player", &self.player, event);
+        callbacks.invoke_table_row_callbacks::<PlayerInput>(
+            "player_input",
+            &self.player_input,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerTickSchedule>(
+            "player_tick_schedule",
+            &self.player_tick_schedule,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Track>("track", &self.track, event);
+        callbacks.invoke_table_row_callbacks::<VolcanoEruptionSchedule>(
+            "volcano_eruption_schedule",
+            &self.volcano_eruption_schedule,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WorldConfig>(
+            "world_config",
+            &self.world_config,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WorldMap>("world_map", &self.world_map, event);
     }
 }
 
@@ -927,11 +1237,23 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        admin_table::register_table(client_cache);
+        ban_table::register_table(client_cache);
         block_table::register_table(client_cache);
+        bot_tick_schedule_table::register_table(client_cache);
         bots_table::register_table(client_cache);
+        grid_cell_table::register_table(client_cache);
+        lava_exposure_table::register_table(client_cache);
+        lava_tick_schedule_table::register_table(client_cache);
         leaderboard_table::register_table(client_cache);
         obstacle_table::register_table(client_cache);
+        obstacle_respawn_schedule_table::register_table(client_cache);
         player_table::register_table(client_cache);
+        player_input_table::register_table(client_cache);
+        player_tick_schedule_table::register_table(client_cache);
         track_table::register_table(client_cache);
+        volcano_eruption_schedule_table::register_table(client_cache);
+        world_config_table::register_table(client_cache);
+        world_map_table::register_table(client_cache);
     }
 }
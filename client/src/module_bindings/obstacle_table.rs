@@ -2,6 +2,8 @@
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
 #![allow(unused, clippy::all)]
+use super::obstacle_kind_type::ObstacleKind;
+use super::obstacle_shape_type::ObstacleShape;
 use super::obstacle_type::Obstacle;
 use super::vec_2_type::Vec2;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
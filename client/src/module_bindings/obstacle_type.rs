@@ -4,6 +4,8 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::obstacle_kind_type::ObstacleKind;
+use super::obstacle_shape_type::ObstacleShape;
 use super::vec_2_type::Vec2;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
@@ -11,7 +13,14 @@ use super::vec_2_type::Vec2;
 pub struct Obstacle {
     pub id: u64,
     pub position: Vec2,
+    pub size: Vec2,
+    pub rotation: f32,
+    pub kind: ObstacleKind,
+    pub shape: ObstacleShape,
     pub hp: u32,
+    pub respawn_at: Option<__sdk::Timestamp>,
+    pub chunk_x: i32,
+    pub chunk_y: i32,
 }
 
 impl __sdk::InModule for Obstacle {
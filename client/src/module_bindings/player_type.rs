@@ -19,6 +19,13 @@ pub struct Player {
     pub hook: Hook,
     pub track: Track,
     pub grid: Grid,
+    pub last_steal: __sdk::Timestamp,
+    pub last_obstacle_hit: __sdk::Timestamp,
+    pub hp: u32,
+    pub speed_modifier: f32,
+    pub updated_at: __sdk::Timestamp,
+    pub chunk_x: i32,
+    pub chunk_y: i32,
 }
 
 impl __sdk::InModule for Player {
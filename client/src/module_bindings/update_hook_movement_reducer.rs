@@ -1,133 +0,0 @@
-// THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
-// WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
-
-#![allow(unused, clippy::all)]
-use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
-
-#[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
-#[sats(crate = __lib)]
-pub(super) struct UpdateHookMovementArgs {
-    pub identity: __sdk::Identity,
-    pub width: f32,
-    pub height: f32,
-}
-
-impl From<UpdateHookMovementArgs> for super::Reducer {
-    fn from(args: UpdateHookMovementArgs) -> Self {
-        Self::UpdateHookMovement {
-            identity: args.identity,
-            width: args.width,
-            height: args.height,
-        }
-    }
-}
-
-impl __sdk::InModule for UpdateHookMovementArgs {
-    type Module = super::RemoteModule;
-}
-
-pub struct UpdateHookMovementCallbackId(__sdk::CallbackId);
-
-#[allow(non_camel_case_types)]
-/// Extension trait for access to the reducer `update_hook_movement`.
-///
-/// Implemented for [`super::RemoteReducers`].
-pub trait update_hook_movement {
-    /// Request that the remote module invoke the reducer `update_hook_movement` to run as soon as possible.
-    ///
-    /// This method returns immediately, and errors only if we are unable to send the request.
-    /// The reducer will run asynchronously in the future,
-    ///  and its status can be observed by listening for [`Self::on_update_hook_movement`] callbacks.
-    fn update_hook_movement(
-        &self,
-        identity: __sdk::Identity,
-        width: f32,
-        height: f32,
-    ) -> __sdk::Result<()>;
-    /// Register a callback to run whenever we are notified of an invocation of the reducer `update_hook_movement`.
-    ///
-    /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
-    /// to determine the reducer's status.
-    ///
-    /// The returned [`UpdateHookMovementCallbackId`] can be passed to [`Self::remove_on_update_hook_movement`]
-    /// to cancel the callback.
-    fn on_update_hook_movement(
-        &self,
-        callback: impl FnMut(&super::ReducerEventContext, &__sdk::Identity, &f32, &f32) + Send + 'static,
-    ) -> UpdateHookMovementCallbackId;
-    /// Cancel a callback previously registered by [`Self::on_update_hook_movement`],
-    /// causing it not to run in the future.
-    fn remove_on_update_hook_movement(&self, callback: UpdateHookMovementCallbackId);
-}
-
-impl update_hook_movement for super::RemoteReducers {
-    fn update_hook_movement(
-        &self,
-        identity: __sdk::Identity,
-        width: f32,
-        height: f32,
-    ) -> __sdk::Result<()> {
-        self.imp.call_reducer(
-            "update_hook_movement",
-            UpdateHookMovementArgs {
-                identity,
-                width,
-                height,
-            },
-        )
-    }
-    fn on_update_hook_movement(
-        &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &__sdk::Identity, &f32, &f32)
-            + Send
-            + 'static,
-    ) -> UpdateHookMovementCallbackId {
-        UpdateHookMovementCallbackId(self.imp.on_reducer(
-            "update_hook_movement",
-            Box::new(move |ctx: &super::ReducerEventContext| {
-                let super::ReducerEventContext {
-                    event:
-                        __sdk::ReducerEvent {
-                            reducer:
-                                super::Reducer::UpdateHookMovement {
-                                    identity,
-                                    width,
-                                    height,
-                                },
-                            ..
-                        },
-                    ..
-                } = ctx
-                else {
-                    unreachable!()
-                };
-                callback(ctx, identity, width, height)
-            }),
-        ))
-    }
-    fn remove_on_update_hook_movement(&self, callback: UpdateHookMovementCallbackId) {
-        self.imp
-            .remove_on_reducer("update_hook_movement", callback.0)
-    }
-}
-
-#[allow(non_camel_case_types)]
-#[doc(hidden)]
-/// Extension trait for setting the call-flags for the reducer `update_hook_movement`.
-///
-/// Implemented for [`super::SetReducerFlags`].
-///
-/// This type is currently unstable and may be removed without a major version bump.
-pub trait set_flags_for_update_hook_movement {
-    /// Set the call-reducer flags for the reducer `update_hook_movement` to `flags`.
-    ///
-    /// This type is currently unstable and may be removed without a major version bump.
-    fn update_hook_movement(&self, flags: __ws::CallReducerFlags);
-}
-
-impl set_flags_for_update_hook_movement for super::SetReducerFlags {
-    fn update_hook_movement(&self, flags: __ws::CallReducerFlags) {
-        self.imp
-            .set_call_reducer_flags("update_hook_movement", flags);
-    }
-}
@@ -15,7 +15,7 @@ struct AnimateScale;
 
 pub fn spawn_tags(
     mut commands: Commands,
-    player_query: Query<Entity, With<Player>>,
+    player_query: Query<Entity, Added<Player>>,
     ctx_wrapper: Res<CtxWrapper>,
     username: Res<Username>,
 ) {
@@ -50,8 +50,10 @@ pub fn spawn_tags(
 
     let player_id = ctx_wrapper.ctx.identity();
 
-    // Find player entity to spawn a child with
-    let player_entity = player_query.get_single();
+    // Find player entity to spawn a child with, once it has been spawned
+    let Ok(player_entity) = player_query.get_single() else {
+        return;
+    };
 
     // Demonstrate text wrapping
     let slightly_smaller_text_font = TextFont {
@@ -85,9 +87,7 @@ pub fn spawn_tags(
         .id();
     let nametag = binding;
 
-    commands
-        .entity(player_entity.expect("Could not find player entity"))
-        .add_children(&[nametag]);
+    commands.entity(player_entity).add_children(&[nametag]);
 }
 
 pub fn spawn_opponent_nametag(
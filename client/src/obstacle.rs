@@ -1,37 +1,18 @@
-use crate::common::{CtxWrapper, Obstacle, SpawnedObstacles, MAP_CONFIG, OBSTACLE_CONFIG};
-use crate::db_connection::load_obstacles;
+use crate::common::{CtxWrapper, Obstacle, SpawnedObstacles, OBSTACLE_CONFIG};
+use crate::module_bindings::{ObstacleKind, ObstacleTableAccess};
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
-use rand::random;
-use std::collections::HashSet;
+use spacetimedb_sdk::Table;
 
 pub fn setup_obstacle(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
     asset_server: Res<AssetServer>,
     ctx_wrapper: Res<CtxWrapper>,
     mut query: Query<(Entity, &mut Transform, &Obstacle)>,
     mut spawned: ResMut<SpawnedObstacles>,
 ) {
-    let _window = window_query.get_single().unwrap();
-    let obstacles = load_obstacles(&ctx_wrapper);
-
-    let world_map_size = Vec2::new(
-        MAP_CONFIG.map_size.x as f32 * MAP_CONFIG.tile_size.x / 2.0,
-        MAP_CONFIG.map_size.y as f32 * MAP_CONFIG.tile_size.y / 2.0,
-    );
-    let mut counter = 0;
-
-    for obstacle in obstacles {
-        //if counter >= OBSTACLE_CONFIG.count {
-        //    break;
-        //}
-        //counter += 1;
-
-        let random_x = (random::<f32>() - 0.5) * world_map_size.x; // obstacle.0;
-        let random_y = (random::<f32>() - 0.5) * world_map_size.x; // obstacle.1;
-        let obstacle_id = obstacle.2;
-        let hp = obstacle.3;
+    for obstacle in ctx_wrapper.ctx.db.obstacle().iter() {
+        let obstacle_id = obstacle.id;
+        let hp = obstacle.hp;
 
         // Clean up if it's already spawned but now has 0 HP
         if spawned.ids.contains(&obstacle_id) && hp <= 0 {
@@ -55,23 +36,47 @@ pub fn setup_obstacle(
             continue;
         }
 
-        let valid_x = random_x < MAP_CONFIG.safe_zone_size && random_x > -MAP_CONFIG.safe_zone_size;
-        let valid_y = random_y < MAP_CONFIG.safe_zone_size && random_y > -MAP_CONFIG.safe_zone_size;
-        if valid_x && valid_y {
-            continue;
-        }
-
         // Mark as spawned
         spawned.ids.insert(obstacle_id);
 
+        // Position, size and rotation all come from the server, so every client sees the same layout
+        let size = Vec2::new(obstacle.size.x, obstacle.size.y);
         commands.spawn((
             Sprite {
-                custom_size: Some(OBSTACLE_CONFIG.size),
-                image: asset_server.load(OBSTACLE_CONFIG.path),
+                custom_size: Some(size),
+                image: asset_server.load(obstacle_sprite(&obstacle.kind)),
                 ..default()
             },
-            Transform::from_xyz(random_x, random_y, 1.0),
-            Obstacle { id: obstacle_id },
+            Transform::from_xyz(obstacle.position.x, obstacle.position.y, 1.0)
+                .with_rotation(Quat::from_rotation_z(obstacle.rotation)),
+            Obstacle {
+                id: obstacle_id,
+                size,
+                shape: obstacle.shape,
+            },
         ));
     }
+
+    // Despawn obstacles that left the area of interest
+    for (entity, _, existing_obstacle) in &query {
+        if ctx_wrapper
+            .ctx
+            .db
+            .obstacle()
+            .id()
+            .find(&existing_obstacle.id)
+            .is_none()
+        {
+            commands.entity(entity).despawn();
+            spawned.ids.remove(&existing_obstacle.id);
+        }
+    }
+}
+
+/// Sprite rendered for an obstacle kind.
+pub fn obstacle_sprite(kind: &ObstacleKind) -> &'static str {
+    match kind {
+        ObstacleKind::Volcano => OBSTACLE_CONFIG.volcano_path,
+        ObstacleKind::Rock => OBSTACLE_CONFIG.rock_path,
+    }
 }
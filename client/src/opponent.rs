@@ -4,12 +4,13 @@ use bevy::prelude::{Vec2, Vec3, *};
 
 use crate::{
     block::SpawnedBlocks, common::AttachedBlock, common::Block as BevyBlock, common::PlayerGrid,
-    common::BLOCK_CONFIG, common::GRID_CONFIG, grid::increment_grid_pos, module_bindings::*,
+    common::BLOCK_CONFIG, common::GRID_CONFIG, module_bindings::*,
 };
 use crate::{
     common::{CtxWrapper, LastTrackPos, Opponent, OpponentTrack, Track, TRACK_CONFIG},
     module_bindings::*,
 };
+use crate::interpolation::{InterpolationBuffer, Snapshot};
 use rand::Rng;
 use spacetimedb_sdk::{Identity, Table};
 use std::collections::HashMap;
@@ -17,7 +18,7 @@ use std::collections::HashMap;
 pub fn spawn_opponent(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
-    query: &Query<(&mut Transform, &Opponent)>,
+    query: &Query<(&mut InterpolationBuffer, &Opponent)>,
     id: &Identity,
     x: f32,
     y: f32,
@@ -53,34 +54,26 @@ pub fn spawn_opponent(
             id: *id,
         },
         LastTrackPos(Vec2::new(x, y)),
+        InterpolationBuffer::default(),
         PlayerGrid {
             block_position: HashMap::new(),
             grid_size: GRID_CONFIG.grid_size,
             cell_size: GRID_CONFIG.cell_size,
-            next_free_pos: GRID_CONFIG.next_free_pos,
             capacity: GRID_CONFIG.capacity,
             load: GRID_CONFIG.load,
         },
     ));
 }
 
+/// Buffers the latest position of an opponent, "interpolate_remote_entities" renders it slightly in the past.
 pub fn update_opponent(
-    query: &mut Query<(&mut Transform, &Opponent)>,
+    query: &mut Query<(&mut InterpolationBuffer, &Opponent)>,
     id: &Identity,
-    x: f32,
-    y: f32,
-    rotation: f32,
+    snapshot: Snapshot,
 ) {
-    for (mut transform, opponent) in query.iter_mut() {
+    for (mut buffer, opponent) in query.iter_mut() {
         if opponent.id == *id {
-            transform.translation.x = x;
-            transform.translation.y = y;
-            transform.rotation = Quat::from_rotation_z(rotation).normalize();
-            //let temp_id = opponent.identity.to_u256() % 10_000;
-            //if temp_id == 9573 {
-            //    print!("{}: {}   ", temp_id, player.position.rotation);
-            //}
-            //println!("Updated opponent {:?} to position ({}, {})", id, x, y);
+            buffer.push(snapshot);
         }
     }
 }
@@ -258,13 +251,10 @@ pub fn setup_blocks_opponent(
                             Transform::from_xyz(0., 0., 1.0),
                             BevyBlock {},
                             AttachedBlock {
-                                grid_offset: (block.offset_x, block.offset_y), //bot_grid.next_free_pos,
+                                grid_offset: (block.offset_x, block.offset_y),
                                 player_entity: opponent_entity,
                             },
                         ));
-                        // Increase next free position when loading from server
-                        grid.next_free_pos = grid.find_next_free_pos().unwrap();
-                        //increment_grid_pos(&mut grid);
                         spawned_blocks.ids.insert(block.id);
                         spawned_blocks.entities.insert(block_entity.id(), block.id);
                     }
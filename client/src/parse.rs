@@ -1,5 +1,5 @@
 // Command line parsing
-use crate::db_connection::DB_NAME;
+use crate::common::ServerProfile;
 use clap::Parser;
 use dirs::*;
 use once_cell::sync::Lazy;
@@ -17,12 +17,17 @@ struct Args {
     #[arg(short, long, default_value = "3000")]
     port: u16,
 
+    /// Name of the published module
+    #[arg(long, default_value = "test")]
+    module: String,
+
     // Clear token
     #[clap(long, short, action)]
     clear: bool,
 }
 
-pub fn parse_args() -> String {
+/// Server selected in the server browser until another one is picked.
+pub fn parse_server_profile() -> ServerProfile {
     let args = Args::parse();
     // Validate the IP address
     //if let Err(e) = args.ip.parse::<std::net::Ipv4Addr>() {
@@ -33,7 +38,7 @@ pub fn parse_args() -> String {
             dirs::home_dir()
                 .unwrap()
                 .join(".spacetimedb_client_credentials")
-                .join(DB_NAME)
+                .join(&args.module)
         });
         if token_path.exists() {
             fs::remove_file(&*token_path).expect("Failed to remove authentication token file");
@@ -45,7 +50,9 @@ pub fn parse_args() -> String {
         }
     }
 
-    println!("http://{}:{}", args.ip, args.port);
-    // Construct the connection URL
-    format!("http://{}:{}", args.ip, args.port)
+    ServerProfile {
+        host: args.ip,
+        port: args.port,
+        module: args.module,
+    }
 }
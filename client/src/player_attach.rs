@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::block::SpawnedBlocks;
 use crate::module_bindings::*;
-use crate::common::{AttachedBlock, Bot, Hook, Player, PlayerAttach, PlayerGrid, PLAYER_CONFIG, CtxWrapper, Opponent, Block};
+use crate::common::{AttachedBlock, Bot, Hook, Obstacle, Player, PlayerAttach, PlayerGrid, PLAYER_CONFIG, CtxWrapper, Opponent, Block};
 use spacetimedb_sdk::{
     credentials, DbContext, Error, Event, Identity, Status, Table, TableWithPrimaryKey,
 };
@@ -126,7 +126,6 @@ pub fn update_block_owner(
 pub fn attach_items(
     player_query: Query<(&Transform, &PlayerGrid), With<Player>>,
     mut items_query: Query<(&PlayerAttach, &mut Transform), Without<Player>>,
-    ctx_wrapper: Res<CtxWrapper>,
 ) {
     //if let Ok(player_transform) = player_query.get_single() {
     for (player_transform, player_grid) in player_query.iter() {
@@ -139,20 +138,39 @@ pub fn attach_items(
             // Update position and rotation
             transform.translation = player_transform.translation + rotated_offset;
             transform.rotation = player_transform.rotation;
+        }
+    }
+}
 
-            let x = transform.translation.x;
-            let y = transform.translation.y;
-            let rotation = transform.rotation.to_euler(EulerRot::XYZ).2;
-
-            ctx_wrapper
-                .ctx
-                .reducers()
-                .update_hook_position(
-                    ctx_wrapper.ctx.identity(),
-                    vec_2_type::Vec2 { x: x, y: y },
-                    rotation,
-                )
-                .unwrap();
+pub fn check_obstacle_collision(
+    new_pos: bevy::prelude::Vec2,
+    obstacles: &Query<(&Transform, &Obstacle)>,
+    origin_size: bevy::prelude::Vec2,
+) -> bool {
+    let origin_radius = origin_size.x.min(origin_size.y) / 2.0;
+
+    obstacles
+        .iter()
+        .any(|(transform, obstacle)| obstacle_distance(new_pos, transform, obstacle) < origin_radius)
+}
+
+/// Distance from "point" to the edge of the obstacle's shape, 0 inside the obstacle.
+/// Same shapes as "obstacle_distance" on the server.
+pub fn obstacle_distance(
+    point: bevy::prelude::Vec2,
+    transform: &Transform,
+    obstacle: &Obstacle,
+) -> f32 {
+    let offset = point - transform.translation.truncate();
+    match obstacle.shape {
+        ObstacleShape::Circle => {
+            (offset.length() - obstacle.size.x.min(obstacle.size.y) / 2.0).max(0.0)
+        }
+        ObstacleShape::Box => {
+            // Rotate the point into the box's local frame
+            let local = (transform.rotation.inverse() * offset.extend(0.0)).truncate();
+            let outside = (local.abs() - obstacle.size / 2.0).max(bevy::prelude::Vec2::ZERO);
+            outside.length()
         }
     }
 }
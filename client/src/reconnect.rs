@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::DbContext;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::block::SpawnedBlocks;
+use crate::common::{
+    AreaOfInterest, Block, Bot, CtxWrapper, GameState, Hook, HookHead, HookRange, Leaderboard,
+    MapTerrain, Obstacle, Opponent, OpponentHook, OpponentHookHead, OpponentTrack, Player,
+    ServerProfile, SpawnedObstacles, TilemapChunks, Track, Username, RECONNECT_CONFIG,
+};
+use crate::db_connection::{connect_to_db, subscribe_to_tables};
+use crate::interpolation::ServerClock;
+use crate::map::TilemapChunk;
+use crate::module_bindings::{set_name, PlayerTableAccess};
+
+/// Connection changes reported by the SpacetimeDB callbacks, which run on the connection's thread.
+pub enum ConnectionEvent {
+    Connected,
+    Lost(String),
+    // Outcome of our own "set_name" call, with the server's reason when rejected
+    NameAccepted,
+    NameRejected(String),
+}
+
+// Events with the generation of the connection that reported them
+static CONNECTION_EVENTS: Mutex<Vec<(u64, ConnectionEvent)>> = Mutex::new(Vec::new());
+// Generation of the newest connection, bumped by "next_connection_generation"
+static CONNECTION_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Starts a new connection generation. Events of older connections are dropped from then on,
+/// so a replaced connection that reports late cannot disturb the new one.
+pub fn next_connection_generation() -> u64 {
+    CONNECTION_GENERATION.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Queues an event of connection "generation" for "handle_connection_events", callable from any thread.
+pub fn push_connection_event(generation: u64, event: ConnectionEvent) {
+    CONNECTION_EVENTS.lock().unwrap().push((generation, event));
+}
+
+/// Takes the queued events of the newest connection, in the order they arrived.
+pub fn take_connection_events() -> Vec<ConnectionEvent> {
+    let current = CONNECTION_GENERATION.load(Ordering::SeqCst);
+    std::mem::take(&mut *CONNECTION_EVENTS.lock().unwrap())
+        .into_iter()
+        .filter(|(generation, _)| *generation == current)
+        .map(|(_, event)| event)
+        .collect()
+}
+
+/// Drops events left over from an earlier connection.
+pub fn clear_connection_events() {
+    CONNECTION_EVENTS.lock().unwrap().clear();
+}
+
+/// Where the client is in reconnecting to the server.
+#[derive(Resource, Default)]
+pub enum ReconnectState {
+    #[default]
+    Connected,
+    // Waiting out the backoff before attempt number "attempt"
+    Waiting {
+        attempt: u32,
+        timer: Timer,
+    },
+    // Attempt "attempt" is built, waiting for the server to accept it
+    Connecting {
+        attempt: u32,
+    },
+    // Retries ran out, the game went back to the menu
+    Offline,
+}
+
+impl ReconnectState {
+    /// Waits before attempt "attempt", doubling the delay every attempt.
+    pub fn waiting(attempt: u32) -> Self {
+        let delay = (RECONNECT_CONFIG.base_delay_secs * 2f32.powi(attempt as i32))
+            .min(RECONNECT_CONFIG.max_delay_secs);
+        ReconnectState::Waiting {
+            attempt,
+            timer: Timer::new(Duration::from_secs_f32(delay), TimerMode::Once),
+        }
+    }
+}
+
+// Marks the "reconnecting" overlay
+#[derive(Component)]
+pub struct ReconnectOverlay;
+
+/// Moves the reconnect state machine on from the queued connection events.
+/// A reconnect that got through resubscribes and sets the name again, as "setup_connection" does.
+pub fn handle_connection_events(
+    mut state: ResMut<ReconnectState>,
+    ctx_wrapper: Res<CtxWrapper>,
+    mut area: ResMut<AreaOfInterest>,
+    username: Option<Res<Username>>,
+) {
+    for event in take_connection_events() {
+        match (event, &*state) {
+            (ConnectionEvent::Connected, ReconnectState::Connecting { attempt }) => {
+                println!("Reconnected after {} attempt(s)", attempt + 1);
+                subscribe_to_tables(&ctx_wrapper.ctx);
+                // Subscribed again around the player by "update_area_of_interest"
+                *area = AreaOfInterest::default();
+                if let Some(username) = &username {
+                    let _ = ctx_wrapper.ctx.reducers.set_name(username.name.clone());
+                }
+                *state = ReconnectState::Connected;
+            }
+            (ConnectionEvent::Connected, _) => {}
+            (ConnectionEvent::Lost(reason), ReconnectState::Connected) => {
+                eprintln!("Connection lost: {}", reason);
+                *state = ReconnectState::waiting(0);
+            }
+            (ConnectionEvent::Lost(reason), ReconnectState::Connecting { attempt }) => {
+                eprintln!("Reconnect attempt {} failed: {}", attempt + 1, reason);
+                *state = ReconnectState::waiting(attempt + 1);
+            }
+            // Already waiting or given up
+            (ConnectionEvent::Lost(_), _) => {}
+            (ConnectionEvent::NameAccepted, _) => {}
+            // Someone took the name while we were away, keep playing under the old one
+            (ConnectionEvent::NameRejected(reason), _) => {
+                eprintln!("Name rejected after reconnecting: {}", reason);
+            }
+        }
+    }
+}
+
+/// Builds a new connection to the selected server once the backoff is over.
+/// When the retries run out the game goes back to the menu.
+pub fn retry_connection(
+    mut state: ResMut<ReconnectState>,
+    mut ctx_wrapper: ResMut<CtxWrapper>,
+    profile: Res<ServerProfile>,
+    mut game_state: ResMut<NextState<GameState>>,
+    time: Res<Time>,
+) {
+    let ReconnectState::Waiting { attempt, timer } = &mut *state else {
+        return;
+    };
+    if !timer.tick(time.delta()).finished() {
+        return;
+    }
+
+    let attempt = *attempt;
+    if attempt >= RECONNECT_CONFIG.max_attempts {
+        eprintln!("Giving up reconnecting after {} attempts", attempt);
+        *state = ReconnectState::Offline;
+        game_state.set(GameState::Menu);
+        return;
+    }
+
+    // The old connection's thread would keep running and report on its own
+    let _ = ctx_wrapper.ctx.disconnect();
+    match connect_to_db(&profile) {
+        Ok(ctx) => {
+            ctx.run_threaded();
+            ctx_wrapper.ctx = ctx;
+            *state = ReconnectState::Connecting { attempt };
+        }
+        Err(err) => {
+            eprintln!("Reconnect attempt {} failed: {:?}", attempt + 1, err);
+            *state = ReconnectState::waiting(attempt + 1);
+        }
+    }
+}
+
+/// Leaves the game when an admin kicked or banned the player. The server cannot close the
+/// connection, it marks the player offline instead, and reconnecting would be refused.
+pub fn leave_when_removed(
+    ctx_wrapper: Res<CtxWrapper>,
+    mut state: ResMut<ReconnectState>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    if !matches!(*state, ReconnectState::Connected) {
+        return;
+    }
+    let Some(identity) = ctx_wrapper.ctx.try_identity() else {
+        return;
+    };
+    let removed = ctx_wrapper
+        .ctx
+        .db
+        .player()
+        .identity()
+        .find(&identity)
+        .is_some_and(|player| !player.online);
+    if removed {
+        eprintln!("Removed from the game by an admin");
+        let _ = ctx_wrapper.ctx.disconnect();
+        *state = ReconnectState::Offline;
+        game_state.set(GameState::Menu);
+    }
+}
+
+/// Shows the "reconnecting" overlay while reconnecting.
+pub fn update_reconnect_overlay(
+    mut commands: Commands,
+    state: Res<ReconnectState>,
+    mut overlay_query: Query<(Entity, &mut Text), With<ReconnectOverlay>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let attempt = match &*state {
+        ReconnectState::Waiting { attempt, .. } | ReconnectState::Connecting { attempt } => {
+            *attempt
+        }
+        ReconnectState::Connected | ReconnectState::Offline => {
+            for (entity, _) in overlay_query.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            return;
+        }
+    };
+
+    let message = format!(
+        "Reconnecting... ({}/{})",
+        (attempt + 1).min(RECONNECT_CONFIG.max_attempts),
+        RECONNECT_CONFIG.max_attempts
+    );
+    if let Ok((_, mut text)) = overlay_query.get_single_mut() {
+        text.0 = message;
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            GlobalZIndex(10),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(message),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                ReconnectOverlay,
+            ));
+        });
+}
+
+/// Clears the game world after giving up reconnecting, so the next game starts from scratch.
+/// The dead connection is dropped, pressing "Play" opens a new one.
+pub fn teardown_offline_game(
+    mut commands: Commands,
+    mut state: ResMut<ReconnectState>,
+    game_query: Query<
+        Entity,
+        Or<(
+            With<Player>,
+            With<Hook>,
+            With<HookHead>,
+            With<HookRange>,
+            With<Block>,
+            With<Bot>,
+            With<Obstacle>,
+            With<Opponent>,
+            With<OpponentHook>,
+            With<OpponentHookHead>,
+            With<Track>,
+            With<OpponentTrack>,
+            With<Leaderboard>,
+            With<TilemapChunk>,
+        )>,
+    >,
+) {
+    if !matches!(*state, ReconnectState::Offline) {
+        return;
+    }
+
+    for entity in game_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.insert_resource(SpawnedBlocks::default());
+    commands.insert_resource(SpawnedObstacles::default());
+    commands.insert_resource(AreaOfInterest::default());
+    commands.insert_resource(ServerClock::default());
+    commands.remove_resource::<MapTerrain>();
+    commands.remove_resource::<TilemapChunks>();
+    commands.remove_resource::<CtxWrapper>();
+    clear_connection_events();
+    *state = ReconnectState::Connected;
+}
@@ -0,0 +1,482 @@
+// Server browser in the main menu: pick, edit, save and test the server to play on
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use std::fs;
+
+use crate::common::{
+    config_file, MenuButtonAction, MenuState, OnServersMenuScreen, ServerProfile, StatusText,
+};
+use crate::db_connection::test_connection;
+use crate::start_menu::{BACKGROUND_COLOR, NORMAL_BUTTON, TEXT_COLOR};
+
+/// Connection test started with the "Test" button, running off the main thread.
+#[derive(Resource)]
+pub struct ConnectionTest {
+    url: String,
+    task: Task<Result<(), String>>,
+}
+
+/// Servers saved in the server browser, stored in the user's config dir.
+#[derive(Resource, Default)]
+pub struct ServerProfiles(pub Vec<ServerProfile>);
+
+/// Server being edited in the server browser, and the field keyboard input goes to.
+#[derive(Resource)]
+pub struct ServerForm {
+    pub host: String,
+    pub port: String,
+    pub module: String,
+    pub focused: Option<ServerField>,
+}
+
+impl ServerForm {
+    fn from_profile(profile: &ServerProfile) -> Self {
+        ServerForm {
+            host: profile.host.clone(),
+            port: profile.port.to_string(),
+            module: profile.module.clone(),
+            focused: None,
+        }
+    }
+
+    /// Profile in the form, or what is wrong with it.
+    fn profile(&self) -> Result<ServerProfile, String> {
+        if self.host.is_empty() {
+            return Err("Host is empty".to_string());
+        }
+        let port = self
+            .port
+            .parse::<u16>()
+            .map_err(|_| format!("Invalid port \"{}\"", self.port))?;
+        if self.module.is_empty() {
+            return Err("Module name is empty".to_string());
+        }
+        Ok(ServerProfile {
+            host: self.host.clone(),
+            port,
+            module: self.module.clone(),
+        })
+    }
+
+    fn field_mut(&mut self, field: ServerField) -> &mut String {
+        match field {
+            ServerField::Host => &mut self.host,
+            ServerField::Port => &mut self.port,
+            ServerField::Module => &mut self.module,
+        }
+    }
+}
+
+// Editable text field of the server form
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ServerField {
+    Host,
+    Port,
+    Module,
+}
+
+impl ServerField {
+    fn label(self) -> &'static str {
+        match self {
+            ServerField::Host => "Host",
+            ServerField::Port => "Port",
+            ServerField::Module => "Module",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            ServerField::Host => ServerField::Port,
+            ServerField::Port => ServerField::Module,
+            ServerField::Module => ServerField::Host,
+        }
+    }
+
+    fn accepts(self, c: char) -> bool {
+        match self {
+            ServerField::Port => c.is_ascii_digit(),
+            ServerField::Host | ServerField::Module => !c.is_whitespace() && !c.is_control(),
+        }
+    }
+}
+
+// Actions of the buttons on the server browser screen
+#[derive(Component)]
+pub enum ServerButtonAction {
+    // Load saved profile number "index" into the form
+    Select(usize),
+    Save,
+    Delete,
+    Test,
+    // Play on the server in the form
+    Use,
+}
+
+// Node the saved profiles are listed in
+#[derive(Component)]
+pub struct ServerList;
+
+/// Reads the saved profiles, one "host port module" line each.
+pub fn load_server_profiles() -> ServerProfiles {
+    let Some(contents) = config_file("servers").and_then(|path| fs::read_to_string(path).ok())
+    else {
+        return ServerProfiles::default();
+    };
+
+    let mut profiles = Vec::new();
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        match parts.as_slice() {
+            [host, port, module] => match port.parse::<u16>() {
+                Ok(port) => profiles.push(ServerProfile {
+                    host: host.to_string(),
+                    port,
+                    module: module.to_string(),
+                }),
+                Err(_) => eprintln!("Ignoring saved server with invalid port: {}", line),
+            },
+            _ => eprintln!("Ignoring malformed saved server: {}", line),
+        }
+    }
+    ServerProfiles(profiles)
+}
+
+fn save_server_profiles(profiles: &ServerProfiles) -> Result<(), String> {
+    let path = config_file("servers").ok_or("No config directory found".to_string())?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+    }
+    let contents: String = profiles
+        .0
+        .iter()
+        .map(|p| format!("{} {} {}\n", p.host, p.port, p.module))
+        .collect();
+    fs::write(path, contents).map_err(|err| err.to_string())
+}
+
+pub fn servers_menu_setup(mut commands: Commands, profile: Res<ServerProfile>) {
+    commands.insert_resource(ServerForm::from_profile(&profile));
+
+    let button_node = Node {
+        width: Val::Px(150.0),
+        height: Val::Px(50.0),
+        margin: UiRect::all(Val::Px(10.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let text_font = TextFont {
+        font_size: 25.0,
+        ..default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            OnServersMenuScreen,
+            BackgroundColor(BACKGROUND_COLOR),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Servers"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+            ));
+
+            // Filled by "update_server_list"
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                },
+                ServerList,
+            ));
+
+            // Host, port and module fields, texts are set by "update_server_fields"
+            for field in [ServerField::Host, ServerField::Port, ServerField::Module] {
+                parent
+                    .spawn(Node {
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(field.label()),
+                            text_font.clone(),
+                            TextColor(TEXT_COLOR),
+                            Node {
+                                width: Val::Px(100.0),
+                                ..default()
+                            },
+                        ));
+                        parent.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(400.0),
+                                ..button_node.clone()
+                            },
+                            BackgroundColor(NORMAL_BUTTON),
+                            Text::default(),
+                            text_font.clone(),
+                            TextColor(TEXT_COLOR),
+                            field,
+                        ));
+                    });
+            }
+
+            parent
+                .spawn(Node {
+                    margin: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (action, text) in [
+                        (ServerButtonAction::Test, "Test"),
+                        (ServerButtonAction::Save, "Save"),
+                        (ServerButtonAction::Delete, "Delete"),
+                        (ServerButtonAction::Use, "Use"),
+                    ] {
+                        parent
+                            .spawn((
+                                Button,
+                                button_node.clone(),
+                                BackgroundColor(NORMAL_BUTTON),
+                                action,
+                            ))
+                            .with_child((
+                                Text::new(text),
+                                text_font.clone(),
+                                TextColor(TEXT_COLOR),
+                            ));
+                    }
+                });
+
+            parent.spawn((
+                Text::default(),
+                text_font.clone(),
+                TextColor(TEXT_COLOR),
+                StatusText,
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    button_node,
+                    BackgroundColor(NORMAL_BUTTON),
+                    MenuButtonAction::BackToMainMenu,
+                ))
+                .with_child((Text::new("Back"), text_font, TextColor(TEXT_COLOR)));
+        });
+}
+
+/// Lists a button per saved profile, again whenever the profiles change.
+pub fn update_server_list(
+    mut commands: Commands,
+    profiles: Res<ServerProfiles>,
+    list_query: Query<(Entity, Ref<ServerList>)>,
+) {
+    for (entity, list) in list_query.iter() {
+        if !profiles.is_changed() && !list.is_added() {
+            continue;
+        }
+        commands.entity(entity).despawn_descendants();
+        commands.entity(entity).with_children(|parent| {
+            if profiles.0.is_empty() {
+                parent.spawn((Text::new("No saved servers"), TextColor(TEXT_COLOR)));
+            }
+            for (index, profile) in profiles.0.iter().enumerate() {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(500.0),
+                            height: Val::Px(40.0),
+                            margin: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        ServerButtonAction::Select(index),
+                    ))
+                    .with_child((
+                        Text::new(format!(
+                            "{}:{} / {}",
+                            profile.host, profile.port, profile.module
+                        )),
+                        TextColor(TEXT_COLOR),
+                    ));
+            }
+        });
+    }
+}
+
+/// Focuses the clicked field, typing then edits it. Tab moves to the next field.
+pub fn edit_server_fields(
+    mut form: ResMut<ServerForm>,
+    field_query: Query<(&Interaction, &ServerField), Changed<Interaction>>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+) {
+    for (interaction, field) in field_query.iter() {
+        if *interaction == Interaction::Pressed {
+            form.focused = Some(*field);
+        }
+    }
+
+    for event in keyboard_events.read() {
+        let Some(field) = form.focused else {
+            continue;
+        };
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(text) => {
+                let value = form.field_mut(field);
+                for c in text.chars().filter(|c| field.accepts(*c)) {
+                    value.push(c);
+                }
+            }
+            Key::Backspace => {
+                form.field_mut(field).pop();
+            }
+            Key::Tab => form.focused = Some(field.next()),
+            Key::Enter | Key::Escape => form.focused = None,
+            _ => {}
+        }
+    }
+}
+
+/// Shows the form in its fields, with a cursor in the focused one.
+pub fn update_server_fields(
+    form: Res<ServerForm>,
+    mut field_query: Query<(&ServerField, &mut Text)>,
+) {
+    if !form.is_changed() {
+        return;
+    }
+    for (field, mut text) in field_query.iter_mut() {
+        let value = match field {
+            ServerField::Host => &form.host,
+            ServerField::Port => &form.port,
+            ServerField::Module => &form.module,
+        };
+        text.0 = if form.focused == Some(*field) {
+            format!("{}|", value)
+        } else {
+            value.clone()
+        };
+    }
+}
+
+pub fn server_button_action(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &ServerButtonAction), Changed<Interaction>>,
+    mut status_query: Query<&mut Text, With<StatusText>>,
+    mut form: ResMut<ServerForm>,
+    mut profiles: ResMut<ServerProfiles>,
+    mut selected: ResMut<ServerProfile>,
+    mut menu_state: ResMut<NextState<MenuState>>,
+) {
+    for (interaction, action) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let status = match action {
+            ServerButtonAction::Select(index) => match profiles.0.get(*index) {
+                Some(profile) => {
+                    *form = ServerForm::from_profile(profile);
+                    String::new()
+                }
+                None => continue,
+            },
+            ServerButtonAction::Save => match form.profile() {
+                Ok(profile) => {
+                    if !profiles.0.contains(&profile) {
+                        profiles.0.push(profile);
+                    }
+                    match save_server_profiles(&profiles) {
+                        Ok(()) => "Saved".to_string(),
+                        Err(err) => format!("Failed to save servers: {}", err),
+                    }
+                }
+                Err(err) => err,
+            },
+            ServerButtonAction::Delete => match form.profile() {
+                Ok(profile) => {
+                    profiles.0.retain(|saved| *saved != profile);
+                    match save_server_profiles(&profiles) {
+                        Ok(()) => "Deleted".to_string(),
+                        Err(err) => format!("Failed to save servers: {}", err),
+                    }
+                }
+                Err(err) => err,
+            },
+            // Reported by "poll_connection_test" when done
+            ServerButtonAction::Test => match form.profile() {
+                Ok(profile) => {
+                    let url = profile.url();
+                    let task =
+                        AsyncComputeTaskPool::get().spawn(async move { test_connection(&profile) });
+                    commands.insert_resource(ConnectionTest {
+                        url: url.clone(),
+                        task,
+                    });
+                    format!("Testing {}...", url)
+                }
+                Err(err) => err,
+            },
+            ServerButtonAction::Use => match form.profile() {
+                Ok(profile) => {
+                    *selected = profile;
+                    menu_state.set(MenuState::Main);
+                    String::new()
+                }
+                Err(err) => err,
+            },
+        };
+
+        for mut text in status_query.iter_mut() {
+            text.0 = status.clone();
+        }
+    }
+}
+
+/// Shows the outcome of the connection test once it is done.
+pub fn poll_connection_test(
+    mut commands: Commands,
+    test: Option<ResMut<ConnectionTest>>,
+    mut status_query: Query<&mut Text, With<StatusText>>,
+) {
+    let Some(mut test) = test else {
+        return;
+    };
+    let Some(result) = block_on(future::poll_once(&mut test.task)) else {
+        return;
+    };
+
+    let status = match result {
+        Ok(()) => format!("{} is up", test.url),
+        Err(err) => format!("Could not connect: {}", err),
+    };
+    for mut text in status_query.iter_mut() {
+        text.0 = status.clone();
+    }
+    commands.remove_resource::<ConnectionTest>();
+}
@@ -3,9 +3,20 @@
 
 use crate::common::*;
 use crate::common::*;
+use crate::db_connection::connect_to_db;
+use crate::module_bindings::set_name;
+use crate::reconnect::{clear_connection_events, take_connection_events, ConnectionEvent};
+use crate::server_browser::{
+    edit_server_fields, poll_connection_test, server_button_action, servers_menu_setup,
+    update_server_fields, update_server_list,
+};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
 use bevy::prelude::*;
 use bevy::text::*;
 use bevy::{app::AppExit, color::palettes::css::CRIMSON, prelude::*};
+use spacetimedb_sdk::DbContext;
+use std::fs;
 
 use rand::prelude::IndexedRandom;
 use rand::seq::SliceRandom;
@@ -121,6 +132,22 @@ pub fn menu_plugin(app: &mut App) {
             OnExit(MenuState::Settings),
             despawn_screen::<OnSettingsMenuScreen>,
         )
+        .add_systems(OnEnter(MenuState::Servers), servers_menu_setup)
+        .add_systems(
+            OnExit(MenuState::Servers),
+            despawn_screen::<OnServersMenuScreen>,
+        )
+        .add_systems(
+            Update,
+            (
+                server_button_action,
+                poll_connection_test,
+                edit_server_fields,
+                update_server_list,
+                update_server_fields.after(edit_server_fields),
+            )
+                .run_if(in_state(MenuState::Servers)),
+        )
         .add_systems(
             OnEnter(MenuState::SettingsDisplay),
             display_settings_menu_setup,
@@ -146,6 +173,14 @@ pub fn menu_plugin(app: &mut App) {
         .add_systems(
             Update,
             (menu_action, button_system).run_if(in_state(GameState::Menu)),
+        )
+        .add_systems(
+            Update,
+            (
+                edit_username.run_if(in_state(MenuState::Main)),
+                join_game.run_if(resource_exists::<CtxWrapper>),
+            )
+                .run_if(in_state(GameState::Menu)),
         );
 }
 
@@ -192,7 +227,8 @@ pub fn menu_setup(mut menu_state: ResMut<NextState<MenuState>>) {
 pub fn main_menu_setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    //username: Res<Username>,
+    profile: Res<ServerProfile>,
+    username: Res<Username>,
 ) {
     // Common style for all buttons on the screen
     let button_node = Node {
@@ -228,43 +264,6 @@ pub fn main_menu_setup(
         ..default()
     };
 
-    pub fn print_random_string(commands: &mut Commands) -> String {
-        let usernames_first = [
-            "cool", "lazy", "sneaky", "noisy", "rusty", "clever", "sleepy", "simple", "nuclear",
-            "rowdy", "grumpy", "shady", "quick", "twitchy", "silent", "spunky", "clumsy", "ugly",
-            "mighty", "smart", "trusty", "wild", "scrummy", "loud", "howling", "boring", "slow",
-        ];
-
-        let usernames_second = [
-            "gear", "cog", "motor", "circuit", "valve", "bot", "servo", "engine", "module", "chain",
-            "piston", "drone", "terminal", "chip", "sensor", "wheel", "pump", "turbine", "rope", "link",
-            "switch", "coal", "hook", "fire", "city", "town", "gate", "platoon", "monster", "castle",
-            "pipe", "screw", "bolt",
-        ];
-
-        let mut rng_first = thread_rng();
-        let mut rng_second = thread_rng();
-
-        let Some(random_username_first) = usernames_first.choose(&mut rng_first) else {
-            todo!()
-        };
-        let Some(random_username_second) = usernames_second.choose(&mut rng_second) else {
-            todo!()
-        };
-        let random_username = format!("{}-{}", random_username_first, random_username_second);
-
-        //commands.insert_resource(Username {
-        //    name: random_username.clone().to_string(),
-        //});
-
-        // println!("{}", random_username);
-        return random_username.to_string();
-    }
-
-    let name = print_random_string(&mut commands);
-    let mut username = name.clone();
-    commands.insert_resource(Username { name: name.clone() });
-
     commands
         .spawn((
             Node {
@@ -297,20 +296,20 @@ pub fn main_menu_setup(
                         },
                     ));
 
-                    // Display username
+                    // Username field, click it to type a new name
                     parent
                         .spawn((
                             Button,
                             button_node.clone(),
-                            BackgroundColor(PRESSED_BUTTON),
-                            username = name.clone(),
-                            // MenuButtonAction::Settings,
+                            BackgroundColor(NORMAL_BUTTON),
+                            UsernameField,
                         ))
                         .with_children(|parent| {
                             parent.spawn((
-                                Text::new(name.clone()),
+                                Text::new(username.name.clone()),
                                 username_button_text_font.clone(),
-                                TextColor(PRESSED_BUTTON),
+                                TextColor(TEXT_COLOR),
+                                UsernameText,
                             ));
                         });
 
@@ -346,6 +345,37 @@ pub fn main_menu_setup(
                                 TextColor(TEXT_COLOR),
                             ));
                         });
+
+                    // Server "Play" connects to, picked in the server browser
+                    parent.spawn((
+                        Text::new(format!(
+                            "{}:{} / {}",
+                            profile.host, profile.port, profile.module
+                        )),
+                        username_button_text_font.clone(),
+                        TextColor(TEXT_COLOR),
+                    ));
+                    parent.spawn((
+                        Text::default(),
+                        username_button_text_font.clone(),
+                        TextColor(TEXT_COLOR),
+                        StatusText,
+                    ));
+
+                    parent
+                        .spawn((
+                            Button,
+                            button_node.clone(),
+                            BackgroundColor(NORMAL_BUTTON),
+                            MenuButtonAction::Servers,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("Servers"),
+                                button_text_font.clone(),
+                                TextColor(TEXT_COLOR),
+                            ));
+                        });
                     // parent
                     //     .spawn((
                     //         Button,
@@ -629,9 +659,14 @@ pub fn menu_action(
         (&Interaction, &MenuButtonAction),
         (Changed<Interaction>, With<Button>),
     >,
+    mut commands: Commands,
     mut app_exit_events: EventWriter<AppExit>,
     mut menu_state: ResMut<NextState<MenuState>>,
     mut game_state: ResMut<NextState<GameState>>,
+    mut status_query: Query<&mut Text, With<StatusText>>,
+    profile: Res<ServerProfile>,
+    username: Res<Username>,
+    joining: Option<Res<CtxWrapper>>,
 ) {
     for (interaction, menu_button_action) in &interaction_query {
         if *interaction == Interaction::Pressed {
@@ -639,10 +674,37 @@ pub fn menu_action(
                 MenuButtonAction::Quit => {
                     app_exit_events.send(AppExit::Success);
                 }
+                // The connection is only opened here, "join_game" enters the game once
+                // the server accepted the name
                 MenuButtonAction::Play => {
-                    game_state.set(GameState::Game);
-                    menu_state.set(MenuState::Disabled);
+                    if joining.is_some() {
+                        continue;
+                    }
+                    clear_connection_events();
+                    let message = match connect_to_db(&profile) {
+                        Ok(ctx) => {
+                            ctx.run_threaded();
+                            match ctx.reducers.set_name(username.name.trim().to_string()) {
+                                Ok(()) => {
+                                    commands.insert_resource(CtxWrapper { ctx });
+                                    "Joining...".to_string()
+                                }
+                                Err(err) => {
+                                    let _ = ctx.disconnect();
+                                    format!("Could not set name: {}", err)
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            eprintln!("Failed to connect to database: {:?}", err);
+                            format!("Could not connect to {}: {}", profile.url(), err)
+                        }
+                    };
+                    for mut text in status_query.iter_mut() {
+                        text.0 = message.clone();
+                    }
                 }
+                MenuButtonAction::Servers => menu_state.set(MenuState::Servers),
                 MenuButtonAction::Settings => menu_state.set(MenuState::Settings),
                 MenuButtonAction::SettingsDisplay => {
                     menu_state.set(MenuState::SettingsDisplay);
@@ -665,40 +727,140 @@ pub fn despawn_screen<T: Component>(to_despawn: Query<Entity, With<T>>, mut comm
     }
 }
 
-// KEYBOARD????
-
-// #[derive(Resource, Default)]
-// struct Username {
-//     current: String,
-// }
-
-// // Marker component for the UI text we want to update
-// #[derive(Component)]
-// struct UsernameText;
-
-// fn handle_keyboard_input(
-//     mut char_evr: EventReader<ReceivedCharacter>,
-//     keys: Res<Input<KeyCode>>,
-//     mut username: ResMut<Username>,
-// ) {
-//     for ev in char_evr.iter() {
-//         if !ev.char.is_control() {
-//             username.current.push(ev.char);
-//         }
-//     }
-
-//     // Handle backspace
-//     if keys.just_pressed(KeyCode::Back) {
-//         username.current.pop();
-//     }
-// }
-
-// fn update_text_display(
-//     username: Res<Username>,
-//     mut query: Query<&mut Text, With<UsernameText>>,
-// ) {
-//     if username.is_changed() {
-//         let mut text = query.single_mut();
-//         text.sections[0].value = format!("Enter Username: {}", username.current);
-//     }
-// }
+// ###################################### USERNAME ##########################################
+
+// Marks the username field on the main menu
+#[derive(Component)]
+pub struct UsernameField;
+
+// Marks the text showing the username
+#[derive(Component)]
+pub struct UsernameText;
+
+/// Name from the last game, or a random "adjective-noun" name on the first start.
+pub fn load_username() -> Username {
+    let saved = config_file("username")
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    Username {
+        name: saved.unwrap_or_else(random_username),
+    }
+}
+
+fn save_username(username: &Username) -> Result<(), String> {
+    let path = config_file("username").ok_or("No config directory found".to_string())?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+    }
+    fs::write(path, &username.name).map_err(|err| err.to_string())
+}
+
+fn random_username() -> String {
+    let usernames_first = [
+        "cool", "lazy", "sneaky", "noisy", "rusty", "clever", "sleepy", "simple", "nuclear",
+        "rowdy", "grumpy", "shady", "quick", "twitchy", "silent", "spunky", "clumsy", "ugly",
+        "mighty", "smart", "trusty", "wild", "scrummy", "loud", "howling", "boring", "slow",
+    ];
+
+    let usernames_second = [
+        "gear", "cog", "motor", "circuit", "valve", "bot", "servo", "engine", "module", "chain",
+        "piston", "drone", "terminal", "chip", "sensor", "wheel", "pump", "turbine", "rope",
+        "link", "switch", "coal", "hook", "fire", "city", "town", "gate", "platoon", "monster",
+        "castle", "pipe", "screw", "bolt",
+    ];
+
+    let mut rng = thread_rng();
+
+    // Only empty word lists have nothing to choose from
+    let (Some(random_username_first), Some(random_username_second)) = (
+        usernames_first.choose(&mut rng),
+        usernames_second.choose(&mut rng),
+    ) else {
+        return "new-player".to_string();
+    };
+    format!("{}-{}", random_username_first, random_username_second)
+}
+
+/// Clicking the username field focuses it, typing then edits the name until Enter or Escape.
+pub fn edit_username(
+    mut username: ResMut<Username>,
+    field_query: Query<&Interaction, (Changed<Interaction>, With<UsernameField>)>,
+    mut text_query: Query<&mut Text, With<UsernameText>>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut focused: Local<bool>,
+) {
+    let was_focused = *focused;
+    if field_query
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        *focused = true;
+    }
+
+    for event in keyboard_events.read() {
+        if !*focused || event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(text) => {
+                for c in text.chars().filter(|c| !c.is_control()) {
+                    if username.name.chars().count() < USERNAME_MAX_LENGTH {
+                        username.name.push(c);
+                    }
+                }
+            }
+            Key::Backspace => {
+                username.name.pop();
+            }
+            Key::Enter | Key::Escape => *focused = false,
+            _ => {}
+        }
+    }
+
+    if username.is_changed() || *focused != was_focused {
+        for mut text in text_query.iter_mut() {
+            text.0 = if *focused {
+                format!("{}|", username.name)
+            } else {
+                username.name.clone()
+            };
+        }
+    }
+}
+
+/// Waits for the server to accept the name after pressing "Play". An accepted name is saved
+/// and the game starts, otherwise the reason is shown and the connection dropped.
+pub fn join_game(
+    mut commands: Commands,
+    ctx_wrapper: Res<CtxWrapper>,
+    mut username: ResMut<Username>,
+    mut status_query: Query<&mut Text, With<StatusText>>,
+    mut menu_state: ResMut<NextState<MenuState>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for event in take_connection_events() {
+        let reason = match event {
+            ConnectionEvent::Connected => continue,
+            ConnectionEvent::NameAccepted => {
+                // The server trims the name, show it the same way
+                username.name = username.name.trim().to_string();
+                if let Err(err) = save_username(&username) {
+                    eprintln!("Failed to save username: {}", err);
+                }
+                game_state.set(GameState::Game);
+                menu_state.set(MenuState::Disabled);
+                return;
+            }
+            ConnectionEvent::NameRejected(reason) => reason,
+            ConnectionEvent::Lost(reason) => format!("Connection lost: {}", reason),
+        };
+
+        for mut text in status_query.iter_mut() {
+            text.0 = reason.clone();
+        }
+        let _ = ctx_wrapper.ctx.disconnect();
+        commands.remove_resource::<CtxWrapper>();
+        return;
+    }
+}
@@ -1,13 +1,112 @@
 use spacetimedb::{
+    rand::Rng,
     reducer,
     spacetimedb_lib::{db, identity},
-    table, DbContext, Identity, Local, ReducerContext, SpacetimeType, Table, Timestamp,
+    table, DbContext, Identity, Local, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp,
 };
 
+// Defaults for the "world_config" table, used when the module is first published.
 const N_BOTS: u64 = 50;
-const N_OBSTACLES: u64 = 200;
+const N_OBSTACLES: u64 = 150;
+const OBSTACLE_HP: u32 = 100;
+const OBSTACLE_SEEDS: (u32, u32) = (21, 1345);
+const OBSTACLE_BLOCK_REWARD: u32 = 5;
+const OBSTACLE_RESPAWN_DELAY_MICROS: i64 = 60_000_000;
+
+// Obstacle kinds, see "obstacle_kind_data". Sizes mirror the client's obstacle sprites.
+const VOLCANO_SIZE: f32 = 400.0;
+const ROCK_SIZE: (f32, f32) = (300.0, 200.0);
+// Rocks have twice the configured obstacle HP and only take damage from heavy hooks.
+const ROCK_HP_FACTOR: u32 = 2;
+const ROCK_MIN_HOOK_LOAD: i32 = 10;
+// Volcanoes erupt periodically, knocking off every block within range.
+const VOLCANO_ERUPTION_MICROS: u64 = 5_000_000;
+const VOLCANO_ERUPTION_RANGE: f32 = 200.0;
+
+// Hook, core and grid dimensions, mirrored from the client's HOOK_CONFIG/PLAYER_CONFIG/GRID_CONFIG.
+const HOOK_MAX_RANGE: f32 = 400.0;
+const HOOK_RADIUS: f32 = 5.0;
+const BLOCK_RADIUS: f32 = 40.0;
+const PLAYER_SIZE: (f32, f32) = (80.0, 160.0);
+const GRID_CELL_SIZE: f32 = 79.0;
+const GRID_SIZE: (i32, i32) = (2, 10);
+// Every grid slot "find_free_slot" can hand out, the core's own cell excluded.
+const MAX_BLOCK_COUNT: usize = ((2 * GRID_SIZE.0 + 1) * (GRID_SIZE.1 + 1) - 1) as usize;
+// Slack added to server-side distance checks to absorb network latency.
+const RANGE_TOLERANCE: f32 = 100.0;
+// Player simulation, mirrored from the client's PLAYER_CONFIG and HOOK_CONFIG.
+// Each block slows the core down by "SPEED_LOSS_PER_BLOCK".
+const PLAYER_TICK_MICROS: u64 = 50_000;
+const PLAYER_MOVEMENT_SPEED: f32 = 300.0;
+const PLAYER_ROTATION_SPEED: f32 = 2.0 * std::f32::consts::PI / 3.0;
+const SPEED_LOSS_PER_BLOCK: f32 = 0.1;
+const HOOK_WIDTH: f32 = 80.0;
+const HOOK_SPEED: f32 = 500.0;
+const HOOK_EXTEND_SPEED: f32 = 500.0;
+const HOOK_RETRACT_SPEED: f32 = 500.0;
+// The client's hook cooldown timer is ticked twice a frame, so its 5 seconds last 2.5.
+const HOOK_FIRE_COOLDOWN_MICROS: i64 = 2_500_000;
+// Minimum time between two steal attempts from the same player.
+const STEAL_COOLDOWN_MICROS: i64 = 500_000;
+// Minimum time between two obstacle hits from the same player, and the damage of a hit.
+const OBSTACLE_HIT_COOLDOWN_MICROS: i64 = 100_000;
+const OBSTACLE_HIT_DAMAGE: u32 = 1;
+// Blocks needed for each extra point of obstacle damage.
+const BLOCKS_PER_EXTRA_DAMAGE: i32 = 10;
+
+// Bot simulation, mirrored from the client's BOT_CONFIG and OBSTACLE_CONFIG.
+const BOT_TICK_MICROS: u64 = 100_000;
+const BOT_RADIUS: f32 = 40.0;
+const BOT_MOVEMENT_SPEED: f32 = 20.0;
+const BOT_ROTATION_SPEED: f32 = std::f32::consts::PI;
+// Bot behaviour, how far bots look for players and loose blocks and how often they hook.
+const BOT_SIGHT_RANGE: f32 = 800.0;
+const BOT_HOOK_COOLDOWN_MICROS: i64 = 2_000_000;
+const BOT_MAX_BLOCK_COUNT: usize = 20;
+
+// Bot lifecycle, how long a stripped bot stays dead and how many blocks it respawns with.
+const BOT_RESPAWN_DELAY_MICROS: i64 = 10_000_000;
+const BLOCKS_PER_BOT: u32 = 10;
+// Bot spawn points, generated from a seed and kept away from obstacles, lava and water.
+const BOT_SPAWN_SEED: u32 = 4242;
+const BOT_SPAWN_ATTEMPTS: u64 = 32;
+const BOT_SPAWN_OBSTACLE_SPACING: f32 = 100.0;
+// Distance between fallback spawn points, used when no candidate is valid.
+const BOT_FALLBACK_SPACING: f32 = 100.0;
+// Half the side of the square around the origin where nothing is generated.
+const SAFE_ZONE_SIZE: f32 = 300.0;
+
+// Side of the area-of-interest chunks, mirrored from the client's AOI_CONFIG.
+// Rows carry their chunk so clients only subscribe to the chunks around them.
+const AOI_CHUNK_SIZE: f32 = 2048.0;
+
+// Lava hazard, checked every "LAVA_TICK_MICROS". Blocks over lava for "LAVA_BURN_MICROS" burn off,
+// and a core over lava loses "LAVA_CORE_DAMAGE" HP per tick, regaining "CORE_REGEN" elsewhere.
+const LAVA_TICK_MICROS: u64 = 500_000;
+const LAVA_BURN_MICROS: i64 = 2_000_000;
+const CORE_MAX_HP: u32 = 100;
+const LAVA_CORE_DAMAGE: u32 = 5;
+const CORE_REGEN: u32 = 1;
+
+// Player names, trimmed and checked by "validate_name". Only letters, digits, '-' and '_' are allowed,
+// and the banned words are matched without case against the words of the name, see "name_words".
+const NAME_MIN_LENGTH: usize = 3;
+const NAME_MAX_LENGTH: usize = 16;
+const BANNED_NAME_WORDS: [&str; 15] = [
+    "fuck", "shit", "cunt", "bitch", "whore", "slut", "nigger", "nigga", "fag", "faggot", "rape",
+    "rapist", "nazi", "penis", "asshole",
+];
+
+// How long a kicked player is refused, so a kick is not undone by reconnecting right away.
+const KICK_BAN_MICROS: i64 = 60_000_000;
+
+
+mod terrain;
 
 use noise::{NoiseFn, Perlin};
+use terrain::{Footprint, Terrain, TileKind};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::rc::Rc;
 
 /// Player component data
 #[spacetimedb::table(name = player, public)]
@@ -21,6 +120,41 @@ pub struct Player {
     hook: Hook,
     track: Track,
     grid: Grid,
+    // Time of the last steal attempt, used for the hook cooldown.
+    last_steal: Timestamp,
+    // Time of the last obstacle hit, used for the obstacle damage cooldown.
+    last_obstacle_hit: Timestamp,
+    // Core HP, lost over lava. At 0 the core is wrecked and drops every block.
+    hp: u32,
+    // Terrain speed multiplier under the core and its blocks, see "player_speed_modifier".
+    speed_modifier: f32,
+    // Server time of the last simulation step, clients interpolate between these.
+    updated_at: Timestamp,
+    // Area-of-interest chunk, kept up to date by the server, see "chunk_of".
+    #[index(btree)]
+    chunk_x: i32,
+    chunk_y: i32,
+}
+
+/// Latest controls sent by a player, applied every "player_tick". Private to the server.
+#[spacetimedb::table(name = player_input)]
+pub struct PlayerInput {
+    #[primary_key]
+    identity: Identity,
+    // Forward/backward and left/right, each in -1..1
+    throttle: f32,
+    turn: f32,
+    // Hook is charged while held and fired when released
+    hook_charge: bool,
+}
+
+/// Schedule driving the "player_tick" reducer.
+#[spacetimedb::table(name = player_tick_schedule, scheduled(player_tick))]
+pub struct PlayerTickSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
 }
 
 #[derive(Debug, SpacetimeType)]
@@ -36,7 +170,63 @@ pub struct Obstacle {
     #[primary_key]
     id: u64,
     position: Vec2,
+    // Width and height, a circle shape fits inside
+    size: Vec2,
+    rotation: f32,
+    kind: ObstacleKind,
+    shape: ObstacleShape,
     hp: u32,
+    // When a destroyed obstacle comes back, None while it is standing
+    respawn_at: Option<Timestamp>,
+    // Area-of-interest chunk, kept up to date by the server, see "chunk_of".
+    #[index(btree)]
+    chunk_x: i32,
+    chunk_y: i32,
+}
+
+/// Kind of obstacle, decides the sprite the client renders and the per-kind data in "obstacle_kind_data".
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum ObstacleKind {
+    // Erupts periodically, knocking off nearby blocks
+    Volcano,
+    // Tough, only heavy hooks can damage it
+    Rock,
+}
+
+/// Collision shape of an obstacle, rotated and sized by the obstacle's "rotation" and "size".
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum ObstacleShape {
+    Circle,
+    Box,
+}
+
+/// Per-kind obstacle data.
+struct ObstacleKindData {
+    size: Vec2,
+    shape: ObstacleShape,
+    // Multiplier applied to the configured obstacle HP
+    hp_factor: u32,
+    // Grid load a player needs for their hook to damage the obstacle
+    min_hook_load: i32,
+}
+
+/// Schedule driving the "erupt_volcanoes" reducer.
+#[spacetimedb::table(name = volcano_eruption_schedule, scheduled(erupt_volcanoes))]
+pub struct VolcanoEruptionSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+/// Schedule restoring a destroyed obstacle through the "respawn_obstacle" reducer.
+#[spacetimedb::table(name = obstacle_respawn_schedule, scheduled(respawn_obstacle))]
+pub struct ObstacleRespawnSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    obstacle_id: u64,
 }
 
 /// Bot component data
@@ -51,6 +241,134 @@ pub struct Bot {
     movement_dir: Vec3,
     // Rotation direction
     rotation_dir: f32,
+    // Current behaviour, picked every tick in "choose_bot_state"
+    state: BotState,
+    // Time of the last hook attempt, used for the bot hook cooldown.
+    last_hook: Timestamp,
+    // Time the bot lost its last block, used for the respawn delay.
+    died_at: Timestamp,
+    // Server time of the last simulation step, clients interpolate between these.
+    updated_at: Timestamp,
+    // Area-of-interest chunk, kept up to date by the server, see "chunk_of".
+    #[index(btree)]
+    chunk_x: i32,
+    chunk_y: i32,
+}
+
+/// Behaviour state of a bot.
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum BotState {
+    // Drive around and avoid obstacles
+    Wander,
+    // Chase a smaller player and hook their blocks
+    Hunt(Identity),
+    // Run from a larger player
+    Flee(Identity),
+    // Pick up a loose block
+    Collect(u64),
+}
+
+/// World configuration, a single row with id 0.
+/// Generation routines read their parameters from here instead of from constants.
+#[spacetimedb::table(name = world_config, public)]
+pub struct WorldConfig {
+    #[primary_key]
+    id: u32,
+    settings: WorldSettings,
+}
+
+/// Identities allowed to call the admin reducers, see "require_admin".
+/// Seeded with the publisher of the module, admins can grant the role to others.
+#[spacetimedb::table(name = admin, public)]
+pub struct Admin {
+    #[primary_key]
+    identity: Identity,
+    // Admin who granted the role, the publisher granted its own
+    granted_by: Identity,
+}
+
+/// Identities refused by "player_connected". Kicks are bans that expire. Private to the server.
+#[spacetimedb::table(name = ban)]
+pub struct Ban {
+    #[primary_key]
+    identity: Identity,
+    reason: String,
+    banned_by: Identity,
+    // None for a permanent ban
+    expires_at: Option<Timestamp>,
+}
+
+/// Tunable parameters of the world.
+#[derive(Debug, Clone, SpacetimeType)]
+pub struct WorldSettings {
+    n_bots: u64,
+    n_obstacles: u64,
+    blocks_per_bot: u32,
+    bot_respawn_delay_micros: i64,
+    // Seed for the Perlin noise placing the bot spawn points
+    bot_spawn_seed: u32,
+    // Seeds for the Perlin noise placing the obstacles
+    obstacle_seed_x: u32,
+    obstacle_seed_y: u32,
+    obstacle_hp: u32,
+    // Loose blocks dropped when an obstacle is destroyed
+    obstacle_block_reward: u32,
+    obstacle_respawn_delay_micros: i64,
+    // Map the world is played on
+    map: MapSource,
+}
+
+/// Schedule driving the "lava_tick" reducer.
+#[spacetimedb::table(name = lava_tick_schedule, scheduled(lava_tick))]
+pub struct LavaTickSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+/// Blocks currently over lava and since when, private to the server.
+#[spacetimedb::table(name = lava_exposure)]
+pub struct LavaExposure {
+    #[primary_key]
+    block_id: u64,
+    since: Timestamp,
+}
+
+/// Map the world is played on, clients build the same terrain from it.
+/// Copy of the map in "world_config", kept in step by "configure_world".
+#[spacetimedb::table(name = world_map, public)]
+pub struct WorldMap {
+    // Always 0, there is one map per world
+    #[primary_key]
+    id: u32,
+    source: MapSource,
+}
+
+/// Where the terrain of a map comes from.
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum MapSource {
+    // Map image in the client's assets, by file name without extension
+    Image(String),
+    // Procedural map generated from a seed
+    Noise(NoiseMap),
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct NoiseMap {
+    seed: u32,
+    // Map size in tiles
+    width: u32,
+    height: u32,
+}
+
+/// Schedule driving the "bot_tick" reducer.
+#[spacetimedb::table(name = bot_tick_schedule, scheduled(bot_tick))]
+pub struct BotTickSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
 }
 
 #[spacetimedb::table(name = track, public)]
@@ -72,17 +390,41 @@ pub struct Hook {
     width: f32,
     // Dynamicallt adjusted when extended
     height: f32,
+    // Seconds the fire button has been held, the range is decided on release
+    charge_secs: f32,
+    // Length the hook extends to, 0 once reached
+    target_length: f32,
+    // Time the hook was last fired, used for the hook cooldown.
+    last_fire: Timestamp,
 }
 #[spacetimedb::table(name = block, public)]
 pub struct Block {
     offset_x: i32,
     offset_y: i32,
+    // Never reused, so clients do not mistake a new block for one they already spawned
     #[primary_key]
+    #[auto_inc]
     id: u64,
     owner: OwnerType,
+    // World position where the block was dropped, only meaningful for loose blocks
+    position: Vec2,
+    // Area-of-interest chunk, the owner's chunk for attached blocks, see "chunk_of".
+    #[index(btree)]
+    chunk_x: i32,
+    chunk_y: i32,
 }
 
-#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+/// Grid cell occupancy, one row per owned block.
+/// "cell" is unique per owner and offset, so two blocks can never share a grid position.
+#[spacetimedb::table(name = grid_cell)]
+pub struct GridCell {
+    #[primary_key]
+    cell: String,
+    #[unique]
+    block_id: u64,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum OwnerType {
     Bot(u64),
     Player(Identity),
@@ -98,7 +440,7 @@ pub struct BevyTransform {
 }
 
 /// Custom f32 2D vector containing xy-coordinates
-#[derive(Debug, SpacetimeType)]
+#[derive(Debug, Clone, SpacetimeType)]
 pub struct Vec2 {
     x: f32,
     y: f32,
@@ -119,35 +461,131 @@ pub struct Leaderboard {
     top_players: Vec<Identity>, // Array of the top three players
 }
 
-/// Reducer for decreasing a ("id") specific obstacle's HP by "damage" points.
-/// Client invokes this reducer in "handle_obstacle_hit" function when dealing damage to an obstacle with their hook.
-
 #[spacetimedb::reducer]
 /// Clients invoke this reducer to set their user names.
 pub fn set_name(ctx: &ReducerContext, name: String) -> Result<(), String> {
-    let name = validate_name(name)?;
-    if let Some(user) = ctx.db.player().identity().find(ctx.sender) {
-        ctx.db.player().identity().update(Player { name, ..user });
-        Ok(())
-    } else {
-        Err("Cannot set name for unknown user".to_string())
+    let user = require_online(ctx)?;
+    let name = validate_name(ctx, ctx.sender, name)?;
+    ctx.db.player().identity().update(Player { name, ..user });
+    Ok(())
+}
+
+/// Takes a name and checks if it's acceptable as the name of "identity".
+/// The reason a name is rejected is shown to the player.
+fn validate_name(ctx: &ReducerContext, identity: Identity, name: String) -> Result<String, String> {
+    let name = name.trim().to_string();
+    let length = name.chars().count();
+    if length < NAME_MIN_LENGTH || length > NAME_MAX_LENGTH {
+        return Err(format!(
+            "Names must be {} to {} characters long",
+            NAME_MIN_LENGTH, NAME_MAX_LENGTH
+        ));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '_')
+    {
+        return Err(format!(
+            "Names may only contain letters, digits, '-' and '_', not '{}'",
+            c
+        ));
     }
+
+    // Whole words only, so names like "grape" or "trapeze" are fine.
+    let offensive = name_words(&name).iter().any(|word| {
+        BANNED_NAME_WORDS
+            .iter()
+            .any(|banned| word == banned || word.strip_suffix('s') == Some(*banned))
+    });
+    if offensive {
+        return Err("Names must not contain offensive words".to_string());
+    }
+
+    if name_taken(ctx, identity, &name) {
+        return Err(format!("The name \"{}\" is already taken", name));
+    }
+    Ok(name)
 }
 
-/// Takes a name and checks if it's acceptable as a user's name.
-fn validate_name(name: String) -> Result<String, String> {
-    if name.is_empty() {
-        Err("Names must not be empty".to_string())
-    } else {
-        Ok(name)
+/// Lowercase words of a name, split at separators, digits and lowercase-to-uppercase changes.
+/// "BadWord-99" gives "bad" and "word".
+fn name_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        let boundary = !c.is_ascii_alphabetic() || (previous_lower && c.is_ascii_uppercase());
+        if boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if c.is_ascii_alphabetic() {
+            word.push(c.to_ascii_lowercase());
+        }
+        previous_lower = c.is_ascii_lowercase();
+    }
+    if !word.is_empty() {
+        words.push(word);
     }
+    words
+}
+
+/// Whether another online player than "identity" goes by "name", ignoring case.
+fn name_taken(ctx: &ReducerContext, identity: Identity, name: &str) -> bool {
+    ctx.db.player().iter().any(|player| {
+        player.online && player.identity != identity && player.name.eq_ignore_ascii_case(name)
+    })
 }
 
+/// Name of "identity" until it picks one, valid for "validate_name" and unique per identity.
+fn default_name(identity: Identity) -> String {
+    let hex = identity.to_hex().to_string();
+    format!("Player-{}", &hex[hex.len() - 8..])
+}
+
+/// Reducer for hitting a ("id") specific obstacle with the sender's hook.
+/// Client invokes this reducer in "handle_obstacle_hit" function when the hook tip touches an obstacle.
+/// The damage comes from the blocks the sender carries, the server checks hook range and the hit cooldown.
 #[spacetimedb::reducer]
-pub fn damage_obstacle(ctx: &ReducerContext, id: u64, damage: u32) -> Result<(), String> {
+pub fn damage_obstacle(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    let mut player = require_online(ctx)?;
+
     if let Some(mut obstacle) = ctx.db.obstacle().id().find(id) {
-        // Subtract (saturating) obstacle's HP with a specific amount of "damage".
+        if obstacle.hp == 0 {
+            return Err("Obstacle is destroyed".to_string());
+        }
+
+        // Obstacle hit cooldown.
+        let since_last_hit = ctx.timestamp.to_micros_since_unix_epoch()
+            - player.last_obstacle_hit.to_micros_since_unix_epoch();
+        if since_last_hit < OBSTACLE_HIT_COOLDOWN_MICROS {
+            return Err("Hook is on cooldown".to_string());
+        }
+
+        // The tip comes from the hook simulated in "move_hook", same as "steal_block".
+        if player.hook.height <= 0.0 {
+            return Err("Hook is not extended".to_string());
+        }
+        if obstacle_distance(&obstacle, &hook_tip(&player.hook)) > HOOK_RADIUS + RANGE_TOLERANCE {
+            return Err("Hook tip does not touch obstacle".to_string());
+        }
+
+        // Counted from the block table, the grid load is not trusted for damage.
+        let load = count_blocks(ctx, &OwnerType::Player(ctx.sender)) as i32;
+        if load < obstacle_kind_data(obstacle.kind).min_hook_load {
+            return Err("Hook is too light to damage this obstacle".to_string());
+        }
+
+        player.last_obstacle_hit = ctx.timestamp;
+        // Bigger players hit harder.
+        let damage =
+            OBSTACLE_HIT_DAMAGE.saturating_add((load / BLOCKS_PER_EXTRA_DAMAGE).max(0) as u32);
+        ctx.db.player().identity().update(player);
+
+        // Subtract (saturating) obstacle's HP with the damage of the hit.
         obstacle.hp = obstacle.hp.saturating_sub(damage);
+        if obstacle.hp == 0 {
+            destroy_obstacle(ctx, &mut obstacle);
+        }
         // Update column in "obstacle" table.
         ctx.db.obstacle().id().update(obstacle);
         Ok(())
@@ -157,27 +595,186 @@ pub fn damage_obstacle(ctx: &ReducerContext, id: u64, damage: u32) -> Result<(),
     }
 }
 
-/// Reducer for updating a ("identity") specific hook "position" and "rotation" on the map.
-/// Client invokes this reducer in "attach_items" function when reattaching hook to player.
+/// Drops the block reward around a destroyed obstacle and schedules its respawn.
+fn destroy_obstacle(ctx: &ReducerContext, obstacle: &mut Obstacle) {
+    let settings = world_settings(ctx);
+
+    // Scatter the reward in a ring around the obstacle.
+    let ring_radius = obstacle_radius(obstacle) + BLOCK_RADIUS;
+    for i in 0..settings.obstacle_block_reward {
+        let angle = i as f32 / settings.obstacle_block_reward as f32 * std::f32::consts::TAU;
+        mint_block(
+            ctx,
+            Vec2 {
+                x: obstacle.position.x + angle.cos() * ring_radius,
+                y: obstacle.position.y + angle.sin() * ring_radius,
+            },
+        );
+    }
+
+    let respawn_at = Timestamp::from_micros_since_unix_epoch(
+        ctx.timestamp.to_micros_since_unix_epoch() + settings.obstacle_respawn_delay_micros,
+    );
+    obstacle.respawn_at = Some(respawn_at);
+    ctx.db
+        .obstacle_respawn_schedule()
+        .insert(ObstacleRespawnSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(respawn_at),
+            obstacle_id: obstacle.id,
+        });
+}
+
+/// Reducer for restoring a destroyed obstacle to full HP.
+/// Server invokes this reducer through the "obstacle_respawn_schedule" table once the respawn delay has passed.
 #[spacetimedb::reducer]
-pub fn update_hook_position(
-    ctx: &ReducerContext,
-    identity: Identity,
-    position: Vec2,
-    rotation: f32,
-) -> Result<(), String> {
-    // Find requested player by Identity.
-    if let Some(mut player) = ctx.db.player().identity().find(identity) {
-        // Update player hook position and rotation.
-        player.hook.position = position;
-        player.hook.rotation = rotation;
-        // Update column in "player" table.
+pub fn respawn_obstacle(ctx: &ReducerContext, arg: ObstacleRespawnSchedule) -> Result<(), String> {
+    // Only the scheduler may respawn obstacles.
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer respawn_obstacle may only be invoked by the scheduler".to_string());
+    }
+
+    if let Some(mut obstacle) = ctx.db.obstacle().id().find(arg.obstacle_id) {
+        obstacle.hp = obstacle_max_hp(&world_settings(ctx), obstacle.kind);
+        obstacle.respawn_at = None;
+        ctx.db.obstacle().id().update(obstacle);
+    }
+    Ok(())
+}
+
+/// Reducer for erupting every standing volcano, knocking off the blocks within "VOLCANO_ERUPTION_RANGE".
+/// Server invokes this reducer every "VOLCANO_ERUPTION_MICROS" through the "volcano_eruption_schedule" table.
+#[spacetimedb::reducer]
+pub fn erupt_volcanoes(ctx: &ReducerContext, _arg: VolcanoEruptionSchedule) -> Result<(), String> {
+    // Only the scheduler may erupt volcanoes.
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer erupt_volcanoes may only be invoked by the scheduler".to_string());
+    }
+
+    let volcanoes: Vec<Obstacle> = ctx
+        .db
+        .obstacle()
+        .iter()
+        .filter(|o| o.hp > 0 && o.kind == ObstacleKind::Volcano)
+        .collect();
+    if volcanoes.is_empty() {
+        return Ok(());
+    }
+
+    let burnt_blocks: Vec<Block> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.owner != OwnerType::None)
+        .filter(|b| {
+            block_world_position(ctx, b).is_some_and(|position| {
+                volcanoes
+                    .iter()
+                    .any(|volcano| obstacle_distance(volcano, &position) < VOLCANO_ERUPTION_RANGE)
+            })
+        })
+        .collect();
+
+    let mut owners = Vec::new();
+    for block in burnt_blocks {
+        let previous_owner = set_block_owner(ctx, block, OwnerType::None)?;
+        if !owners.contains(&previous_owner) {
+            owners.push(previous_owner);
+        }
+    }
+
+    // Blocks cut off from their core fall off as well.
+    for owner in owners {
+        release_disconnected_blocks(ctx, &owner)?;
+        if let OwnerType::Player(identity) = owner {
+            update_grid_load(ctx, identity);
+        }
+    }
+    Ok(())
+}
+
+/// Reducer for burning off blocks that stayed over lava and damaging cores over lava.
+/// Server invokes this reducer every "LAVA_TICK_MICROS" through the "lava_tick_schedule" table.
+#[spacetimedb::reducer]
+pub fn lava_tick(ctx: &ReducerContext, _arg: LavaTickSchedule) -> Result<(), String> {
+    // Only the scheduler may apply lava damage.
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer lava_tick may only be invoked by the scheduler".to_string());
+    }
+
+    let terrain = terrain(ctx);
+    // Attached blocks, loose blocks do not burn.
+    let mut burnt_owners = Vec::new();
+    let blocks: Vec<Block> = ctx.db.block().iter().collect();
+    for block in blocks {
+        let over_lava = block.owner != OwnerType::None
+            && block_world_position(ctx, &block)
+                .is_some_and(|pos| terrain.tile_at(pos.x, pos.y) == Some(TileKind::Lava));
+        if !over_lava {
+            ctx.db.lava_exposure().block_id().delete(block.id);
+            continue;
+        }
+
+        let Some(exposure) = ctx.db.lava_exposure().block_id().find(block.id) else {
+            ctx.db.lava_exposure().insert(LavaExposure {
+                block_id: block.id,
+                since: ctx.timestamp,
+            });
+            continue;
+        };
+        let exposed_micros = ctx.timestamp.to_micros_since_unix_epoch()
+            - exposure.since.to_micros_since_unix_epoch();
+        if exposed_micros >= LAVA_BURN_MICROS {
+            if !burnt_owners.contains(&block.owner) {
+                burnt_owners.push(block.owner.clone());
+            }
+            burn_block(ctx, block.id);
+        }
+    }
+
+    // Cores, a wrecked core drops every block it carries.
+    let players: Vec<Player> = ctx.db.player().iter().filter(|p| p.online).collect();
+    for mut player in players {
+        let position = &player.position.coordinates;
+        let hp = if terrain.tile_at(position.x, position.y) == Some(TileKind::Lava) {
+            player.hp.saturating_sub(LAVA_CORE_DAMAGE)
+        } else {
+            (player.hp + CORE_REGEN).min(CORE_MAX_HP)
+        };
+        if hp == player.hp {
+            continue;
+        }
+
+        let identity = player.identity;
+        let wrecked = hp == 0;
+        player.hp = if wrecked { CORE_MAX_HP } else { hp };
         ctx.db.player().identity().update(player);
-        Ok(())
-    } else {
-        // Reaches only when requesting a player's hook with an unknown identity.
-        Err("Player not found".to_string())
+
+        if wrecked {
+            let owner = OwnerType::Player(identity);
+            let owned: Vec<Block> = ctx.db.block().iter().filter(|b| b.owner == owner).collect();
+            for block in owned {
+                set_block_owner(ctx, block, OwnerType::None)?;
+            }
+            update_grid_load(ctx, identity);
+        }
     }
+
+    // Blocks cut off by the burnt ones fall off.
+    for owner in burnt_owners {
+        release_disconnected_blocks(ctx, &owner)?;
+        if let OwnerType::Player(identity) = owner {
+            update_grid_load(ctx, identity);
+        }
+    }
+    Ok(())
+}
+
+/// Destroys a block, freeing its grid cell.
+fn burn_block(ctx: &ReducerContext, block_id: u64) {
+    ctx.db.grid_cell().block_id().delete(block_id);
+    ctx.db.lava_exposure().block_id().delete(block_id);
+    ctx.db.block().id().delete(block_id);
 }
 
 #[spacetimedb::reducer]
@@ -190,125 +787,477 @@ pub fn update_tracks_system(
     height: f32,
     id: u64,
 ) -> Result<(), String> {
-    if let Some(_player) = ctx.db.player().identity().find(ctx.sender) {
-        ctx.db.track().insert(Track {
-            owner_identity,
-            position,
-            rotation,
-            width,
-            height,
-            id,
-        });
-        Ok(())
-    } else {
-        Err("Player not found".to_string())
+    if owner_identity != ctx.sender {
+        return Err("Cannot update the tracks of another player".to_string());
     }
+    require_online(ctx)?;
+    ctx.db.track().insert(Track {
+        owner_identity,
+        position,
+        rotation,
+        width,
+        height,
+        id,
+    });
+    Ok(())
 }
 
-/// Reducer for updating a ("identity") specific hook's movement in extension and retraction through it's "width" and "height".
-/// Client invokes this reducer in "hook_controls" function when player extends and retracs hook.
+/// Reducer for storing the controls of a player, the server moves the player from them in "player_tick".
+/// Client invokes this reducer in "send_player_input" function whenever the pressed controls change.
 #[spacetimedb::reducer]
-pub fn update_hook_movement(
+pub fn send_input(
     ctx: &ReducerContext,
-    identity: Identity,
-    width: f32,
-    height: f32,
+    throttle: f32,
+    turn: f32,
+    hook_charge: bool,
 ) -> Result<(), String> {
-    // Find requested player by Identity.
-    if let Some(mut player) = ctx.db.player().identity().find(identity) {
-        // Update player hook width and height.
-        player.hook.width = width;
-        player.hook.height = height;
-        // Update column in "player" table.
-        ctx.db.player().identity().update(player);
-        Ok(())
+    require_online(ctx)?;
+    // Analog controls are clamped, NaN counts as released.
+    let clamp_axis = |value: f32| {
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(-1.0, 1.0)
+        }
+    };
+
+    let input = PlayerInput {
+        identity: ctx.sender,
+        throttle: clamp_axis(throttle),
+        turn: clamp_axis(turn),
+        hook_charge,
+    };
+    if ctx.db.player_input().identity().find(ctx.sender).is_some() {
+        ctx.db.player_input().identity().update(input);
     } else {
-        // Reaches only when requesting a player's hook with an unknown identity.
-        Err("Player not found".to_string())
+        ctx.db.player_input().insert(input);
     }
+    Ok(())
 }
 
-/// Reducer for updating player position by sending the entity data contained in a transform. All data is sent in a custom "BevyTransform" struct.
-/// Client invokes this reducer in "player_movement" function when updating the position of the player sprite.
+/// Reducer for moving every online player and their hook one simulation step from their latest input.
+/// Server invokes this reducer every "PLAYER_TICK_MICROS" through the "player_tick_schedule" table.
 #[spacetimedb::reducer]
-pub fn update_player_position(
+pub fn player_tick(ctx: &ReducerContext, _arg: PlayerTickSchedule) -> Result<(), String> {
+    // Only the scheduler may advance the simulation.
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer player_tick may only be invoked by the scheduler".to_string());
+    }
+
+    let delta_secs = PLAYER_TICK_MICROS as f32 / 1_000_000.0;
+    let obstacles: Vec<Obstacle> = ctx.db.obstacle().iter().filter(|o| o.hp > 0).collect();
+    let inputs: Vec<PlayerInput> = ctx.db.player_input().iter().collect();
+
+    for input in inputs {
+        let Some(mut player) = ctx.db.player().identity().find(input.identity) else {
+            continue;
+        };
+        if !player.online {
+            continue;
+        }
+
+        move_player(ctx, &mut player, &input, &obstacles, delta_secs);
+        move_hook(ctx, &mut player, &input, delta_secs);
+        player.updated_at = ctx.timestamp;
+
+        let chunk = chunk_of(&player.position.coordinates);
+        if chunk != (player.chunk_x, player.chunk_y) {
+            (player.chunk_x, player.chunk_y) = chunk;
+            update_block_chunks(ctx, &OwnerType::Player(player.identity), chunk);
+        }
+        ctx.db.player().identity().update(player);
+    }
+    Ok(())
+}
+
+/// Moves the core of a player one step, same rules as "player_movement" on the client.
+/// A step that would put the core or one of its blocks into an obstacle is dropped.
+fn move_player(
     ctx: &ReducerContext,
-    bevy_transform: BevyTransform,
-) -> Result<(), String> {
-    // Find requested player by Identity.
-    if let Some(_player) = ctx.db.player().identity().find(ctx.sender) {
-        // Update modified column in "player" table.
-        ctx.db.player().identity().update(Player {
-            // Update player position
-            position: bevy_transform,
-            .._player
-        });
-        Ok(())
-    } else {
-        // Reaches only when requesting a player with an unknown identity.
-        Err("Player not found".to_string())
+    player: &mut Player,
+    input: &PlayerInput,
+    obstacles: &[Obstacle],
+    delta_secs: f32,
+) {
+    // The core only turns while driving.
+    if input.throttle == 0.0 {
+        return;
+    }
+
+    let owner = OwnerType::Player(player.identity);
+    let blocks: Vec<Block> = ctx.db.block().iter().filter(|b| b.owner == owner).collect();
+    let scale = 1.0 / (1.0 + blocks.len() as f32 * SPEED_LOSS_PER_BLOCK);
+    let move_speed = PLAYER_MOVEMENT_SPEED * scale * player.speed_modifier;
+    let rot_speed = PLAYER_ROTATION_SPEED * scale;
+
+    // Forward is the core's local +Y.
+    let rotation = player.position.rotation;
+    let step = input.throttle * move_speed * delta_secs;
+    let new_transform = BevyTransform {
+        coordinates: Vec2 {
+            x: player.position.coordinates.x - rotation.sin() * step,
+            y: player.position.coordinates.y + rotation.cos() * step,
+        },
+        rotation: rotation + input.turn * rot_speed * delta_secs,
+        scale: player.position.scale.clone(),
+    };
+
+    let core_radius = PLAYER_SIZE.0.min(PLAYER_SIZE.1) / 2.0;
+    let collides = |point: &Vec2, radius: f32| {
+        obstacles
+            .iter()
+            .any(|obstacle| obstacle_distance(obstacle, point) < radius)
+    };
+    if collides(&new_transform.coordinates, core_radius)
+        || blocks
+            .iter()
+            .any(|b| collides(&attached_block_position(&new_transform, b), BLOCK_RADIUS))
+    {
+        return;
+    }
+
+    // Keep the core on the map.
+    let (half_width, half_height) = terrain(ctx).half_size();
+    let max_x = half_width - PLAYER_SIZE.0 / 2.0;
+    let max_y = half_height - PLAYER_SIZE.1 / 2.0;
+    let mut new_transform = new_transform;
+    new_transform.coordinates.x = new_transform.coordinates.x.clamp(-max_x, max_x);
+    new_transform.coordinates.y = new_transform.coordinates.y.clamp(-max_y, max_y);
+
+    // Blocks follow the new position, so the terrain under them is sampled after moving.
+    player.speed_modifier = player_speed_modifier(ctx, player.identity, &new_transform);
+    player.position = new_transform;
+}
+
+/// Charges, fires, extends and retracts the hook of a player one step, same rules as "hook_controls" on the client.
+fn move_hook(ctx: &ReducerContext, player: &mut Player, input: &PlayerInput, delta_secs: f32) {
+    let hook = &mut player.hook;
+    let since_fire =
+        ctx.timestamp.to_micros_since_unix_epoch() - hook.last_fire.to_micros_since_unix_epoch();
+
+    if since_fire >= HOOK_FIRE_COOLDOWN_MICROS {
+        if input.hook_charge {
+            if hook.target_length == 0.0 {
+                hook.charge_secs += delta_secs;
+            }
+        } else if hook.charge_secs > 0.0 {
+            // Released, fire with the charged range.
+            hook.target_length = (hook.charge_secs / 2.0 * HOOK_SPEED).min(HOOK_MAX_RANGE);
+            hook.charge_secs = 0.0;
+            hook.last_fire = ctx.timestamp;
+        }
+    }
+
+    if hook.target_length > 0.0 {
+        hook.height = (hook.height + HOOK_EXTEND_SPEED * delta_secs).min(hook.target_length);
+        if (hook.height - hook.target_length).abs() < 0.1 {
+            hook.target_length = 0.0;
+        }
+    } else if hook.height > 0.0 && !input.hook_charge {
+        hook.height = (hook.height - HOOK_RETRACT_SPEED * delta_secs).max(0.0);
+    }
+
+    // The hook is mounted on the core.
+    hook.position = player.position.coordinates.clone();
+    hook.rotation = player.position.rotation;
+    hook.width = HOOK_WIDTH;
+}
+
+/// Tip of a hook, "height" along the direction the hook points in from its mount.
+fn hook_tip(hook: &Hook) -> Vec2 {
+    Vec2 {
+        x: hook.position.x - hook.rotation.sin() * hook.height,
+        y: hook.position.y + hook.rotation.cos() * hook.height,
     }
 }
 
+/// Reducer for moving every bot one simulation step.
+/// Server invokes this reducer every "BOT_TICK_MICROS" through the "bot_tick_schedule" table.
 #[spacetimedb::reducer]
-pub fn update_owner_grid(
-    ctx: &ReducerContext,
-    load: i32,
-    next_free_x: i32,
-    next_free_y: i32,
-) -> Result<(), String> {
-    if let Some(_player) = ctx.db.player().identity().find(ctx.sender) {
-        ctx.db.player().identity().update(Player {
-            grid: Grid {
-                load,
-                next_free_x,
-                next_free_y,
-            },
-            .._player
+pub fn bot_tick(ctx: &ReducerContext, _arg: BotTickSchedule) -> Result<(), String> {
+    // Only the scheduler may advance the simulation.
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer bot_tick may only be invoked by the scheduler".to_string());
+    }
+
+    let delta_secs = BOT_TICK_MICROS as f32 / 1_000_000.0;
+    let obstacles: Vec<Obstacle> = ctx.db.obstacle().iter().filter(|o| o.hp > 0).collect();
+    let terrain = terrain(ctx);
+    // Gathered once per tick, and again only after a bot changed who owns what.
+    let mut blocks = blocks_by_owner(ctx);
+
+    for mut bot in ctx.db.bots().iter() {
+        if !bot.alive {
+            if respawn_bot_if_ready(ctx, bot) {
+                blocks = blocks_by_owner(ctx);
+            }
+            continue;
+        }
+
+        // A bot stripped of all its blocks dies.
+        if owned_count(&blocks, &OwnerType::Bot(bot.id)) == 0 {
+            bot.alive = false;
+            bot.died_at = ctx.timestamp;
+            bot.state = BotState::Wander;
+            ctx.db.bots().id().update(bot);
+            continue;
+        }
+
+        bot.state = choose_bot_state(ctx, &bot, &blocks);
+
+        // Heading towards (or away from) the target of the current state.
+        let target = match &bot.state {
+            BotState::Wander => None,
+            BotState::Hunt(identity) | BotState::Flee(identity) => ctx
+                .db
+                .player()
+                .identity()
+                .find(identity)
+                .map(|p| p.position.coordinates),
+            BotState::Collect(block_id) => ctx.db.block().id().find(block_id).map(|b| b.position),
+        };
+        let heading = target.map(|target| {
+            let angle = (target.y - bot.position.coordinates.y)
+                .atan2(target.x - bot.position.coordinates.x);
+            if let BotState::Flee(_) = bot.state {
+                angle + std::f32::consts::PI
+            } else {
+                angle
+            }
         });
-        Ok(())
-    } else {
-        Err("Player not found".to_string())
+
+        steer_bot(ctx, &mut bot, heading, &obstacles, &terrain, delta_secs);
+
+        let hooked = match bot.state.clone() {
+            BotState::Hunt(identity) => {
+                bot_hook_block(ctx, &mut bot, OwnerType::Player(identity), &blocks)
+            }
+            BotState::Collect(_) => bot_hook_block(ctx, &mut bot, OwnerType::None, &blocks),
+            BotState::Wander | BotState::Flee(_) => false,
+        };
+        if hooked {
+            blocks = blocks_by_owner(ctx);
+        }
+
+        bot.updated_at = ctx.timestamp;
+        let chunk = chunk_of(&bot.position.coordinates);
+        if chunk != (bot.chunk_x, bot.chunk_y) {
+            (bot.chunk_x, bot.chunk_y) = chunk;
+            update_block_chunks(ctx, &OwnerType::Bot(bot.id), chunk);
+        }
+        ctx.db.bots().id().update(bot);
     }
+    Ok(())
 }
 
-#[spacetimedb::reducer]
-pub fn decrease_grid_load(
+/// Respawns a dead bot at one of the spawn points with a fresh set of blocks once the respawn delay has passed.
+/// Returns whether the bot was respawned.
+fn respawn_bot_if_ready(ctx: &ReducerContext, mut bot: Bot) -> bool {
+    let settings = world_settings(ctx);
+    let since_death =
+        ctx.timestamp.to_micros_since_unix_epoch() - bot.died_at.to_micros_since_unix_epoch();
+    if since_death < settings.bot_respawn_delay_micros {
+        return false;
+    }
+
+    let index = (ctx.rng().gen::<f32>() * settings.n_bots as f32) as u64;
+    bot.position.coordinates = bot_spawn_position(ctx, &settings, index);
+    bot.position.rotation = 0.0;
+    bot.rotation_dir = 0.0;
+    bot.alive = true;
+    bot.updated_at = ctx.timestamp;
+    (bot.chunk_x, bot.chunk_y) = chunk_of(&bot.position.coordinates);
+
+    // Stored first, the new blocks take the chunk of the spawn point.
+    let bot_id = bot.id;
+    ctx.db.bots().id().update(bot);
+    give_bot_blocks(ctx, bot_id);
+    true
+}
+
+/// Picks the behaviour of a bot from its block count compared to the players around it.
+/// Fleeing from a larger player goes before hunting a smaller one, which goes before collecting loose blocks.
+fn choose_bot_state(
     ctx: &ReducerContext,
-    identity: Identity,
-    load: i32,
-) -> Result<(), String> {
-    if let Some(mut player) = ctx.db.player().identity().find(identity) {
-        player.grid.load = load;
-        ctx.db.player().identity().update(player);
-        Ok(())
-    } else {
-        Err("Player not found".to_string())
+    bot: &Bot,
+    blocks: &HashMap<OwnerType, Vec<Block>>,
+) -> BotState {
+    let bot_pos = &bot.position.coordinates;
+    let bot_count = owned_count(blocks, &OwnerType::Bot(bot.id));
+
+    let mut larger: Option<(f32, Identity)> = None;
+    let mut smaller: Option<(f32, Identity)> = None;
+    for player in ctx.db.player().iter().filter(|p| p.online) {
+        let dist = distance(bot_pos, &player.position.coordinates);
+        if dist > BOT_SIGHT_RANGE {
+            continue;
+        }
+
+        let player_count = owned_count(blocks, &OwnerType::Player(player.identity));
+        let closest = if player_count > bot_count {
+            &mut larger
+        } else if player_count < bot_count && player_count > 0 {
+            &mut smaller
+        } else {
+            continue;
+        };
+        if closest.is_none_or(|(closest_dist, _)| dist < closest_dist) {
+            *closest = Some((dist, player.identity));
+        }
+    }
+
+    if let Some((_, identity)) = larger {
+        return BotState::Flee(identity);
+    }
+    if bot_count >= BOT_MAX_BLOCK_COUNT {
+        return BotState::Wander;
     }
+    if let Some((_, identity)) = smaller {
+        return BotState::Hunt(identity);
+    }
+
+    blocks
+        .get(&OwnerType::None)
+        .into_iter()
+        .flatten()
+        .map(|b| (distance(bot_pos, &b.position), b.id))
+        .filter(|(dist, _)| *dist <= BOT_SIGHT_RANGE)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map_or(BotState::Wander, |(_, block_id)| {
+            BotState::Collect(block_id)
+        })
 }
 
-/// Reducer for updating a ("bot_id") specific bot position by sending the entity data contained in a transform. All data is sent in a custom "BevyTransform" struct, except "new_rotate_dir".
-/// Client invokes this reducer in "render_bots_from_db" function when updating the position of the bot sprite.
-#[spacetimedb::reducer]
-pub fn update_bot_position(
+/// Turns a bot towards "heading" and moves it forward, steering around obstacles and lava.
+fn steer_bot(
     ctx: &ReducerContext,
-    bevy_transform: BevyTransform,
-    bot_id: u64,
-    new_rotate_dir: f32,
-) -> Result<(), String> {
-    // Find requested bot by bot id.
-    if let Some(mut _bot) = ctx.db.bots().iter().find(|b| b.id == bot_id) {
-        // Update bot position and rotation.
-        _bot.position = bevy_transform;
-        _bot.rotation_dir = new_rotate_dir;
+    bot: &mut Bot,
+    heading: Option<f32>,
+    obstacles: &[Obstacle],
+    terrain: &Terrain,
+    delta_secs: f32,
+) {
+    let turn_step = BOT_ROTATION_SPEED * delta_secs;
+    bot.rotation_dir = 0.0;
 
-        // Update column in "bots" table.
-        ctx.db.bots().id().update(_bot);
-        Ok(())
-    } else {
-        // Reaches only when requesting a bot with a unknown identity.
-        Err("Bot not found".to_string())
+    // Turn towards the heading, at most one turn step per tick.
+    if let Some(heading) = heading {
+        let mut diff = (heading - bot.position.rotation) % std::f32::consts::TAU;
+        if diff > std::f32::consts::PI {
+            diff -= std::f32::consts::TAU;
+        } else if diff < -std::f32::consts::PI {
+            diff += std::f32::consts::TAU;
+        }
+        bot.rotation_dir = diff.clamp(-turn_step, turn_step);
+        bot.position.rotation = (bot.position.rotation + bot.rotation_dir) % std::f32::consts::TAU;
+    }
+
+    let rotation = bot.position.rotation;
+    let probe = |angle: f32| {
+        let (sin, cos) = (rotation + angle).sin_cos();
+        Vec2 {
+            x: bot.position.coordinates.x + cos * BOT_RADIUS * 2.0,
+            y: bot.position.coordinates.y + sin * BOT_RADIUS * 2.0,
+        }
+    };
+
+    if !bot_will_collide(&probe(0.0), obstacles, terrain) {
+        // Move along the movement direction, rotated by the bot's rotation.
+        let (sin, cos) = rotation.sin_cos();
+        let dir = &bot.movement_dir;
+        bot.position.coordinates.x += (dir.x * cos - dir.y * sin) * BOT_MOVEMENT_SPEED * delta_secs;
+        bot.position.coordinates.y += (dir.x * sin + dir.y * cos) * BOT_MOVEMENT_SPEED * delta_secs;
+        return;
+    }
+
+    // Try to look left and right.
+    let left_clear = !bot_will_collide(&probe(0.7), obstacles, terrain);
+    let right_clear = !bot_will_collide(&probe(-0.7), obstacles, terrain);
+
+    // Decide which direction to turn.
+    bot.rotation_dir = match (left_clear, right_clear) {
+        (true, false) => turn_step,
+        (false, true) => -turn_step,
+        (true, true) if ctx.rng().gen::<bool>() => turn_step,
+        (true, true) => -turn_step,
+        // Nowhere to go: turn around.
+        (false, false) => std::f32::consts::PI,
+    };
+    bot.position.rotation = (rotation + bot.rotation_dir) % std::f32::consts::TAU;
+}
+
+/// Lets a bot hook a block from "target" when one is within hook range.
+/// Blocks owned by a player go through the same weighted combat as "steal_block", loose blocks are free.
+/// Returns whether any block changed owner.
+fn bot_hook_block(
+    ctx: &ReducerContext,
+    bot: &mut Bot,
+    target: OwnerType,
+    blocks: &HashMap<OwnerType, Vec<Block>>,
+) -> bool {
+    let since_last_hook =
+        ctx.timestamp.to_micros_since_unix_epoch() - bot.last_hook.to_micros_since_unix_epoch();
+    if since_last_hook < BOT_HOOK_COOLDOWN_MICROS {
+        return false;
+    }
+
+    let thief = OwnerType::Bot(bot.id);
+    let thief_count = owned_count(blocks, &thief);
+    if thief_count >= BOT_MAX_BLOCK_COUNT {
+        return false;
+    }
+
+    let in_range: Vec<u64> = blocks
+        .get(&target)
+        .into_iter()
+        .flatten()
+        .filter(|b| {
+            block_world_position(ctx, b).is_some_and(|pos| {
+                distance(&bot.position.coordinates, &pos) <= HOOK_MAX_RANGE + BLOCK_RADIUS
+            })
+        })
+        .map(|b| b.id)
+        .collect();
+    if in_range.is_empty() {
+        return false;
+    }
+
+    bot.last_hook = ctx.timestamp;
+    let owner_count = owned_count(blocks, &target);
+    if target != OwnerType::None && !weighted_combat(ctx, thief_count, owner_count) {
+        return false;
+    }
+
+    let index = (ctx.rng().gen::<f32>() * in_range.len() as f32) as usize;
+    let Some(block) = ctx.db.block().id().find(in_range[index]) else {
+        return false;
+    };
+    // The bot row is written by "bot_tick" afterwards, so the block count is all that changes here.
+    if let Err(err) = set_block_owner(ctx, block, thief.clone()) {
+        log::warn!("Bot {} could not hook block: {}", bot.id, err);
+        return false;
+    }
+    if let Err(err) = release_disconnected_blocks(ctx, &target)
+        .and_then(|_| release_disconnected_blocks(ctx, &thief))
+    {
+        log::warn!("Bot {} could not release blocks: {}", bot.id, err);
     }
+    if let OwnerType::Player(identity) = target {
+        update_grid_load(ctx, identity);
+    }
+    true
+}
+
+/// Checks if a bot at "pos" would hit an obstacle, lava or the map edge.
+fn bot_will_collide(pos: &Vec2, obstacles: &[Obstacle], terrain: &Terrain) -> bool {
+    if obstacles
+        .iter()
+        .any(|obstacle| obstacle_distance(obstacle, pos) < BOT_RADIUS)
+    {
+        return true;
+    }
+
+    terrain.box_touches(pos.x, pos.y, BOT_RADIUS, TileKind::Lava)
 }
 
 /// Function for respawning all bots in server when no player is online.
@@ -322,34 +1271,18 @@ pub fn reset_bots_if_no_players_online(ctx: &ReducerContext) -> Result<(), Strin
         return Ok(());
     }
 
-    // Original bot spawn points. Three bots are spawned.
-    let bot_spawn_positions = vec![
-        (3781.6, 3982.3), (-1084.2, -1641.5), (-245.8, -3738.9), (1158.4, -1953.6), (-3515.9, -2370.0),
-        (2860.4, 2835.7), (1487.7, -1057.9), (-3925.8, 1394.2), (2092.3, 1425.1), (-3414.7, 3169.0),
-        (3751.2, -2789.5), (214.5, 3782.9), (-684.0, -3144.7), (2322.7, -1534.3), (1011.9, 1411.7),
-        (-2176.3, 1239.6), (-3896.4, 2899.7), (104.7, 1012.0), (-3562.4, 189.2), (1051.0, -1079.3),
-        (2061.1, -1014.8), (-2810.7, -2812.2), (3319.9, -3715.4), (-1316.3, -2699.1), (2315.2, 1686.5),
-        (-2419.8, -1351.6), (2736.7, -1127.8), (-312.5, -4080.9), (265.4, 2044.5), (-1453.0, 1005.1),
-        (717.9, -1294.6), (-1363.5, -1946.3), (3225.1, 1739.2), (-2099.7, -271.0), (-1102.2, -3995.3),
-        (-1465.4, 240.2), (1766.5, 2004.4), (-3956.3, -3681.9), (1539.8, 2219.5), (-3150.0, 1269.4),
-        (1742.4, 337.0), (2824.6, -160.3), (355.0, -718.7), (1993.4, 2015.6), (3360.2, -2045.0),
-        (-357.4, 607.6), (1084.0, -2646.2), (1466.9, 3042.2), (398.1, -3590.4), (2993.8, 1849.0),
-    ];
-
-
-
-
-
-
     // Reset each bot (e.g., set them to some default positions).
-    for (i, mut bot) in ctx.db.bots().iter().enumerate() {
-        let (x, y) = bot_spawn_positions.get(i).cloned().unwrap_or((0.0, 0.0));
-
+    let settings = world_settings(ctx);
+    for mut bot in ctx.db.bots().iter() {
         bot.position = BevyTransform {
-            coordinates: Vec2 { x, y },
+            coordinates: bot_spawn_position(ctx, &settings, bot.id),
             rotation: 0.0,
             scale: Vec2 { x: 0.0, y: 0.0 },
         };
+        bot.updated_at = ctx.timestamp;
+        let chunk = chunk_of(&bot.position.coordinates);
+        (bot.chunk_x, bot.chunk_y) = chunk;
+        update_block_chunks(ctx, &OwnerType::Bot(bot.id), chunk);
         // Update column in "bots" table.
         ctx.db.bots().id().update(bot);
     }
@@ -360,13 +1293,25 @@ pub fn reset_bots_if_no_players_online(ctx: &ReducerContext) -> Result<(), Strin
 /// Reducer for creating and/or login existing player to server.
 /// Server invokes this reducer when client establishes connection to server.
 #[spacetimedb::reducer(client_connected)]
-pub fn player_connected(ctx: &ReducerContext) {
+pub fn player_connected(ctx: &ReducerContext) -> Result<(), String> {
+    // Refuse banned and recently kicked players.
+    if let Some(ban) = active_ban(ctx, ctx.sender) {
+        return Err(format!("Banned from this world: {}", ban.reason));
+    }
+
     // Check if returning player.
     if let Some(_player) = ctx.db.player().identity().find(ctx.sender) {
+        // Someone else took the name while this player was away.
+        let name = if name_taken(ctx, ctx.sender, &_player.name) {
+            default_name(ctx.sender)
+        } else {
+            _player.name.clone()
+        };
         // Update modified column in "player" table.
         ctx.db.player().identity().update(Player {
             // Change from offline to online.
             online: true,
+            name,
             .._player
         });
     } else {
@@ -375,8 +1320,8 @@ pub fn player_connected(ctx: &ReducerContext) {
         ctx.db.player().insert(Player {
             // Set player Identity to connecting client.
             identity: ctx.sender,
-            name: "Lorem Ipsum".to_string(),
-            //name,
+            // Replaced by the client through "set_name".
+            name: default_name(ctx.sender),
             // Set default position data.
             position: BevyTransform {
                 coordinates: Vec2 { x: 0.0, y: 0.0 },
@@ -391,6 +1336,9 @@ pub fn player_connected(ctx: &ReducerContext) {
                 rotation: 0.0,
                 width: 0.0,
                 height: 0.0,
+                charge_secs: 0.0,
+                target_length: 0.0,
+                last_fire: Timestamp::UNIX_EPOCH,
             },
             track: Track {
                 owner_identity: ctx.sender,
@@ -409,8 +1357,17 @@ pub fn player_connected(ctx: &ReducerContext) {
                 next_free_x: -1,
                 next_free_y: 0,
             },
+            last_steal: Timestamp::UNIX_EPOCH,
+            last_obstacle_hit: Timestamp::UNIX_EPOCH,
+            hp: CORE_MAX_HP,
+            speed_modifier: 1.0,
+            updated_at: ctx.timestamp,
+            // Spawned at the origin.
+            chunk_x: 0,
+            chunk_y: 0,
         });
     }
+    Ok(())
 }
 
 /// Reducer for logging out player from servers.
@@ -425,6 +1382,8 @@ pub fn player_disconnected(ctx: &ReducerContext) {
             online: false,
             .._player
         });
+        // Stop driving, the controls are sent again after reconnecting.
+        ctx.db.player_input().identity().delete(ctx.sender);
     } else {
         // Reaches only when requesting player with unknown identity.
         // Should never reach!!
@@ -442,14 +1401,418 @@ pub fn player_disconnected(ctx: &ReducerContext) {
 /// Server invokes this reducer during intialization of the server.
 #[spacetimedb::reducer(init)]
 pub fn server_startup(ctx: &ReducerContext) {
+    // The publisher of the module administrates the world.
+    ctx.db.admin().insert(Admin {
+        identity: ctx.sender,
+        granted_by: ctx.sender,
+    });
+    let settings = default_world_settings();
+    // Publish the map, so every client renders the terrain the server checks against.
+    ctx.db.world_map().insert(WorldMap {
+        id: 0,
+        source: settings.map.clone(),
+    });
+    ctx.db
+        .world_config()
+        .insert(WorldConfig { id: 0, settings });
     // Generate obstacles in server.
     generate_obstacles(ctx);
-    // Generate bots in server.
+    // Generate bots and their blocks in server.
     generate_bots(ctx);
-    generate_blocks(ctx);
     generate_leaderboard(ctx);
+    // Start the bot simulation.
+    ctx.db.bot_tick_schedule().insert(BotTickSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(
+            std::time::Duration::from_micros(BOT_TICK_MICROS).into(),
+        ),
+    });
+    // Start the player simulation.
+    ctx.db.player_tick_schedule().insert(PlayerTickSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(
+            std::time::Duration::from_micros(PLAYER_TICK_MICROS).into(),
+        ),
+    });
+    // Start the lava hazard.
+    ctx.db.lava_tick_schedule().insert(LavaTickSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(
+            std::time::Duration::from_micros(LAVA_TICK_MICROS).into(),
+        ),
+    });
+    // Start the volcano eruptions.
+    ctx.db
+        .volcano_eruption_schedule()
+        .insert(VolcanoEruptionSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(
+                std::time::Duration::from_micros(VOLCANO_ERUPTION_MICROS).into(),
+            ),
+        });
+}
+
+/// Reducer for retuning a running world.
+/// Only world admins may invoke this reducer. Bots are added or removed to match "n_bots",
+/// and obstacles are regenerated when their settings change.
+#[spacetimedb::reducer]
+pub fn configure_world(ctx: &ReducerContext, settings: WorldSettings) -> Result<(), String> {
+    require_admin(ctx)?;
+    let Some(mut config) = ctx.db.world_config().id().find(0) else {
+        return Err("World is not configured".to_string());
+    };
+    if settings.blocks_per_bot == 0 || settings.blocks_per_bot as usize > BOT_MAX_BLOCK_COUNT {
+        return Err(format!(
+            "Blocks per bot must be between 1 and {}",
+            BOT_MAX_BLOCK_COUNT
+        ));
+    }
+    if settings.obstacle_hp == 0 {
+        return Err("Obstacles must have HP".to_string());
+    }
+    if settings.obstacle_respawn_delay_micros < 0 || settings.bot_respawn_delay_micros < 0 {
+        return Err("Respawn delays must not be negative".to_string());
+    }
+    terrain::validate_map_source(&settings.map)?;
+
+    let n_bots = settings.n_bots;
+    let old = std::mem::replace(&mut config.settings, settings);
+    let obstacles_changed = old.n_obstacles != config.settings.n_obstacles
+        || old.obstacle_seed_x != config.settings.obstacle_seed_x
+        || old.obstacle_seed_y != config.settings.obstacle_seed_y
+        || old.obstacle_hp != config.settings.obstacle_hp;
+    let map_changed = old.map != config.settings.map;
+    if map_changed {
+        ctx.db.world_map().id().update(WorldMap {
+            id: 0,
+            source: config.settings.map.clone(),
+        });
+    }
+    ctx.db.world_config().id().update(config);
+
+    // Remove bots beyond the new bot count, their blocks are dropped where they stand.
+    let removed_bots: Vec<u64> = ctx
+        .db
+        .bots()
+        .iter()
+        .map(|b| b.id)
+        .filter(|id| *id >= n_bots)
+        .collect();
+    for bot_id in removed_bots {
+        let blocks: Vec<Block> = ctx
+            .db
+            .block()
+            .iter()
+            .filter(|b| b.owner == OwnerType::Bot(bot_id))
+            .collect();
+        for block in blocks {
+            set_block_owner(ctx, block, OwnerType::None)?;
+        }
+        ctx.db.bots().id().delete(bot_id);
+    }
+    generate_bots(ctx);
+
+    // Obstacles are placed around the terrain, so a new map needs new ones.
+    if obstacles_changed || map_changed {
+        regenerate_obstacles(ctx);
+    }
+    Ok(())
+}
+
+/// Replaces every obstacle with newly generated ones.
+fn regenerate_obstacles(ctx: &ReducerContext) {
+    let obstacle_ids: Vec<u64> = ctx.db.obstacle().iter().map(|o| o.id).collect();
+    for id in obstacle_ids {
+        ctx.db.obstacle().id().delete(id);
+    }
+    // Pending respawns belong to the old obstacles.
+    let respawn_ids: Vec<u64> = ctx
+        .db
+        .obstacle_respawn_schedule()
+        .iter()
+        .map(|r| r.scheduled_id)
+        .collect();
+    for id in respawn_ids {
+        ctx.db.obstacle_respawn_schedule().scheduled_id().delete(id);
+    }
+    generate_obstacles(ctx);
+}
+
+/// Fails unless the sender is in the "admin" table.
+fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admin().identity().find(ctx.sender).is_none() {
+        return Err("Only world admins may do this".to_string());
+    }
+    Ok(())
+}
+
+/// Player row of the sender, which must be in the game.
+/// Kicked and banned players stay connected but are marked offline, see "ban_identity".
+fn require_online(ctx: &ReducerContext) -> Result<Player, String> {
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        // Reaches only when requesting a player with an unknown identity.
+        return Err("Player not found".to_string());
+    };
+    if !player.online {
+        return Err("Player is not in the game".to_string());
+    }
+    Ok(player)
+}
+
+/// Ban of "identity" that is still in force, an expired kick is cleared.
+fn active_ban(ctx: &ReducerContext, identity: Identity) -> Option<Ban> {
+    let ban = ctx.db.ban().identity().find(identity)?;
+    let expired = ban.expires_at.as_ref().is_some_and(|expires_at| {
+        expires_at.to_micros_since_unix_epoch() <= ctx.timestamp.to_micros_since_unix_epoch()
+    });
+    if expired {
+        ctx.db.ban().identity().delete(identity);
+        return None;
+    }
+    Some(ban)
+}
+
+/// Bans "identity" until "expires_at", or for good when None, and takes it out of the game.
+/// The server cannot close a connection, so the player is marked offline, which stops
+/// its simulation and makes its client leave. Reconnecting is refused in "player_connected".
+fn ban_identity(
+    ctx: &ReducerContext,
+    identity: Identity,
+    reason: String,
+    expires_at: Option<Timestamp>,
+) -> Result<(), String> {
+    if ctx.db.admin().identity().find(identity).is_some() {
+        return Err("Admins cannot be kicked or banned".to_string());
+    }
+    ctx.db.ban().identity().delete(identity);
+    ctx.db.ban().insert(Ban {
+        identity,
+        reason,
+        banned_by: ctx.sender,
+        expires_at,
+    });
+
+    if let Some(player) = ctx.db.player().identity().find(identity) {
+        ctx.db.player().identity().update(Player {
+            online: false,
+            ..player
+        });
+        ctx.db.player_input().identity().delete(identity);
+        reset_bots_if_no_players_online(ctx)?;
+    }
+    Ok(())
+}
+
+/// Reducer for removing a ("identity") specific player from the game for "KICK_BAN_MICROS".
+/// Only world admins may invoke this reducer.
+#[spacetimedb::reducer]
+pub fn kick_player(ctx: &ReducerContext, identity: Identity, reason: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.player().identity().find(identity).is_none() {
+        return Err("Player not found".to_string());
+    }
+    let expires_at = Timestamp::from_micros_since_unix_epoch(
+        ctx.timestamp.to_micros_since_unix_epoch() + KICK_BAN_MICROS,
+    );
+    ban_identity(ctx, identity, reason, Some(expires_at))?;
+    log::info!("{:?} kicked {:?}", ctx.sender, identity);
+    Ok(())
+}
+
+/// Reducer for removing a ("identity") specific player from the game for good.
+/// Only world admins may invoke this reducer, "unban_player" lets the player back in.
+#[spacetimedb::reducer]
+pub fn ban_player(ctx: &ReducerContext, identity: Identity, reason: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    ban_identity(ctx, identity, reason, None)?;
+    log::info!("{:?} banned {:?}", ctx.sender, identity);
+    Ok(())
+}
+
+/// Reducer for lifting the ban or kick of a ("identity") specific player.
+/// Only world admins may invoke this reducer.
+#[spacetimedb::reducer]
+pub fn unban_player(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.ban().identity().find(identity).is_none() {
+        return Err("Player is not banned".to_string());
+    }
+    ctx.db.ban().identity().delete(identity);
+    Ok(())
+}
+
+/// Reducer for changing the name of a ("identity") specific player, under the same rules as "set_name".
+/// Only world admins may invoke this reducer.
+#[spacetimedb::reducer]
+pub fn rename_player(ctx: &ReducerContext, identity: Identity, name: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let Some(player) = ctx.db.player().identity().find(identity) else {
+        return Err("Player not found".to_string());
+    };
+    let name = validate_name(ctx, identity, name)?;
+    ctx.db.player().identity().update(Player { name, ..player });
+    Ok(())
+}
+
+/// Reducer for starting the world over. Obstacles, bots and blocks are generated again,
+/// tracks are cleared and every player goes back to the spawn point without blocks.
+/// Only world admins may invoke this reducer.
+#[spacetimedb::reducer]
+pub fn reset_world(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    // Bots get new blocks when generated again, players start without any.
+    let block_ids: Vec<u64> = ctx.db.block().iter().map(|b| b.id).collect();
+    for id in block_ids {
+        burn_block(ctx, id);
+    }
+    let bot_ids: Vec<u64> = ctx.db.bots().iter().map(|b| b.id).collect();
+    for id in bot_ids {
+        ctx.db.bots().id().delete(id);
+    }
+    let track_owners: Vec<Identity> = ctx.db.track().iter().map(|t| t.owner_identity).collect();
+    for owner in track_owners {
+        ctx.db.track().owner_identity().delete(owner);
+    }
+    regenerate_obstacles(ctx);
+    generate_bots(ctx);
+
+    let players: Vec<Player> = ctx.db.player().iter().collect();
+    for mut player in players {
+        player.position = BevyTransform {
+            coordinates: Vec2 { x: 0.0, y: 0.0 },
+            rotation: 0.0,
+            scale: Vec2 { x: 50.0, y: 100.0 },
+        };
+        player.hook.height = 0.0;
+        player.hook.charge_secs = 0.0;
+        player.hook.target_length = 0.0;
+        player.grid = Grid {
+            load: 0,
+            next_free_x: -1,
+            next_free_y: 0,
+        };
+        player.hp = CORE_MAX_HP;
+        player.speed_modifier = 1.0;
+        player.updated_at = ctx.timestamp;
+        (player.chunk_x, player.chunk_y) = chunk_of(&player.position.coordinates);
+        ctx.db.player().identity().update(player);
+    }
+    log::info!("{:?} reset the world", ctx.sender);
+    Ok(())
+}
+
+/// Reducer for giving the admin role to a ("identity") specific identity.
+/// Only world admins may invoke this reducer.
+#[spacetimedb::reducer]
+pub fn grant_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().identity().find(identity).is_some() {
+        return Err("Already an admin".to_string());
+    }
+    ctx.db.admin().insert(Admin {
+        identity,
+        granted_by: ctx.sender,
+    });
+    Ok(())
+}
+
+/// Reducer for taking the admin role from a ("identity") specific identity.
+/// Only world admins may invoke this reducer, the last admin cannot be removed.
+#[spacetimedb::reducer]
+pub fn revoke_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().identity().find(identity).is_none() {
+        return Err("Not an admin".to_string());
+    }
+    if ctx.db.admin().count() <= 1 {
+        return Err("Cannot remove the last admin".to_string());
+    }
+    ctx.db.admin().identity().delete(identity);
+    Ok(())
+}
+
+/// Settings used when the module is first published.
+fn default_world_settings() -> WorldSettings {
+    WorldSettings {
+        n_bots: N_BOTS,
+        n_obstacles: N_OBSTACLES,
+        blocks_per_bot: BLOCKS_PER_BOT,
+        bot_respawn_delay_micros: BOT_RESPAWN_DELAY_MICROS,
+        bot_spawn_seed: BOT_SPAWN_SEED,
+        obstacle_seed_x: OBSTACLE_SEEDS.0,
+        obstacle_seed_y: OBSTACLE_SEEDS.1,
+        obstacle_hp: OBSTACLE_HP,
+        obstacle_block_reward: OBSTACLE_BLOCK_REWARD,
+        obstacle_respawn_delay_micros: OBSTACLE_RESPAWN_DELAY_MICROS,
+        map: terrain::default_map_source(),
+    }
+}
+
+/// Current world settings, falling back to the defaults if the world has not been configured.
+fn world_settings(ctx: &ReducerContext) -> WorldSettings {
+    ctx.db
+        .world_config()
+        .id()
+        .find(0)
+        .map_or_else(default_world_settings, |config| config.settings)
+}
+
+/// Terrain of the map in "world_config".
+fn terrain(ctx: &ReducerContext) -> Rc<Terrain> {
+    match ctx.db.world_config().id().find(0) {
+        Some(config) => terrain::terrain(&config.settings.map),
+        None => terrain::terrain(&terrain::default_map_source()),
+    }
+}
+
+/// Spawn position of bot "bot_id", generated from the bot spawn seed.
+/// Candidates are drawn from Perlin noise until one is outside the safe zone,
+/// clear of obstacles and on neither lava nor water.
+fn bot_spawn_position(ctx: &ReducerContext, settings: &WorldSettings, bot_id: u64) -> Vec2 {
+    let perlin_x = Perlin::new(settings.bot_spawn_seed);
+    let perlin_y = Perlin::new(settings.bot_spawn_seed.wrapping_add(1));
+    let terrain = terrain(ctx);
+    let (half_width, half_height) = terrain.half_size();
+    let obstacles: Vec<Obstacle> = ctx.db.obstacle().iter().filter(|o| o.hp > 0).collect();
+
+    for attempt in 0..BOT_SPAWN_ATTEMPTS {
+        // Offset from the lattice points, where Perlin noise is always zero.
+        let i = bot_id * BOT_SPAWN_ATTEMPTS + attempt;
+        let x = i as f64 / 10.0 + 0.05;
+        let y = (i + 1) as f64 / 10.0 + 0.05;
+
+        let candidate = Vec2 {
+            x: perlin_x.get([x, y]) as f32 * half_width,
+            y: perlin_y.get([y, x]) as f32 * half_height,
+        };
+
+        let in_safe_zone = candidate.x.abs() < SAFE_ZONE_SIZE && candidate.y.abs() < SAFE_ZONE_SIZE;
+        let near_obstacle = obstacles.iter().any(|obstacle| {
+            obstacle_distance(obstacle, &candidate) < BOT_RADIUS + BOT_SPAWN_OBSTACLE_SPACING
+        });
+        let on_hazard = terrain.box_touches(candidate.x, candidate.y, BOT_RADIUS, TileKind::Lava)
+            || terrain.box_touches(candidate.x, candidate.y, BOT_RADIUS, TileKind::Water);
+
+        if !in_safe_zone && !near_obstacle && !on_hazard {
+            return candidate;
+        }
+    }
+
+    // Fall back to columns right of the safe zone, one slot per bot so they do not stack.
+    log::warn!("No valid spawn point found for bot {}", bot_id);
+    let rows = ((2.0 * SAFE_ZONE_SIZE / BOT_FALLBACK_SPACING) as u64).max(1);
+    let (column, row) = (bot_id / rows, bot_id % rows);
+    Vec2 {
+        x: (SAFE_ZONE_SIZE + BOT_RADIUS + column as f32 * BOT_FALLBACK_SPACING)
+            .min(half_width - BOT_RADIUS),
+        y: -SAFE_ZONE_SIZE + BOT_RADIUS + row as f32 * BOT_FALLBACK_SPACING,
+    }
 }
 
+/// Reducer for releasing a block from the sender's grid.
+/// Client invokes this reducer to drop one of its own blocks, blocks left disconnected are dropped with it.
+/// Taking ownership of blocks goes through "steal_block", so the only allowed new owner is "OwnerType::None".
 #[spacetimedb::reducer]
 pub fn update_block_owner(
     ctx: &ReducerContext,
@@ -458,46 +1821,396 @@ pub fn update_block_owner(
     offset_x: i32,
     offset_y: i32,
 ) -> Result<(), String> {
-    if let Some(mut block) = ctx.db.block().id().find(block_id) {
-        block.owner = new_owner;
-        block.offset_x = offset_x;
-        block.offset_y = offset_y;
-        ctx.db.block().id().update(block);
+    // Grid positions are always assigned by "set_block_owner", the offsets are kept for older clients.
+    let _ = (offset_x, offset_y);
+    require_online(ctx)?;
+    if let Some(block) = ctx.db.block().id().find(block_id) {
+        if block.owner != OwnerType::Player(ctx.sender) {
+            return Err("Cannot change owner of a block you do not own".to_string());
+        }
+        if new_owner != OwnerType::None {
+            return Err("Blocks can only be released, use steal_block to take them".to_string());
+        }
+        let previous_owner = set_block_owner(ctx, block, new_owner)?;
+        release_disconnected_blocks(ctx, &previous_owner)?;
+        update_grid_load(ctx, ctx.sender);
         Ok(())
     } else {
         Err("Block does not exist".to_string())
     }
 }
 
-// Function for generating bots in server.
-// Server invokes this function in "server_startup" reducer during server intialization.
-fn generate_bots(ctx: &ReducerContext) {
-    // Example bot generation logic, spawn three bots.
-    let bot_spawn_positions = vec![
-        (3781.6, 3982.3), (-1084.2, -1641.5), (-245.8, -3738.9), (1158.4, -1953.6), (-3515.9, -2370.0),
-        (2860.4, 2835.7), (1487.7, -1057.9), (-3925.8, 1394.2), (2092.3, 1425.1), (-3414.7, 3169.0),
-        (3751.2, -2789.5), (214.5, 3782.9), (-684.0, -3144.7), (2322.7, -1534.3), (1011.9, 1411.7),
-        (-2176.3, 1239.6), (-3896.4, 2899.7), (104.7, 1012.0), (-3562.4, 189.2), (1051.0, -1079.3),
-        (2061.1, -1014.8), (-2810.7, -2812.2), (3319.9, -3715.4), (-1316.3, -2699.1), (2315.2, 1686.5),
-        (-2419.8, -1351.6), (2736.7, -1127.8), (-312.5, -4080.9), (265.4, 2044.5), (-1453.0, 1005.1),
-        (717.9, -1294.6), (-1363.5, -1946.3), (3225.1, 1739.2), (-2099.7, -271.0), (-1102.2, -3995.3),
-        (-1465.4, 240.2), (1766.5, 2004.4), (-3956.3, -3681.9), (1539.8, 2219.5), (-3150.0, 1269.4),
-        (1742.4, 337.0), (2824.6, -160.3), (355.0, -718.7), (1993.4, 2015.6), (3360.2, -2045.0),
-        (-357.4, 607.6), (1084.0, -2646.2), (1466.9, 3042.2), (398.1, -3590.4), (2993.8, 1849.0),
-    ];
+/// Reducer for taking a ("block_id") specific block with the sender's hook.
+/// Client invokes this reducer in "hook_collision_system" function when the hook tip touches a block.
+/// The server checks its own hook tip and the cooldown, rolls the weighted combat and picks the grid slot.
+#[spacetimedb::reducer]
+pub fn steal_block(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
+    let mut player = require_online(ctx)?;
+    let Some(block) = ctx.db.block().id().find(block_id) else {
+        return Err("Block does not exist".to_string());
+    };
+
+    let thief = OwnerType::Player(ctx.sender);
+    if block.owner == thief {
+        return Err("Block is already owned by player".to_string());
+    }
+
+    // Hook cooldown.
+    let since_last_steal =
+        ctx.timestamp.to_micros_since_unix_epoch() - player.last_steal.to_micros_since_unix_epoch();
+    if since_last_steal < STEAL_COOLDOWN_MICROS {
+        return Err("Hook is on cooldown".to_string());
+    }
+
+    // The tip comes from the hook simulated in "move_hook", not from the client.
+    if player.hook.height <= 0.0 {
+        return Err("Hook is not extended".to_string());
+    }
+    let hook_tip = hook_tip(&player.hook);
+
+    // The hook tip has to actually touch the block.
+    let Some(block_pos) = block_world_position(ctx, &block) else {
+        return Err("Block owner is not in the world".to_string());
+    };
+    if distance(&block_pos, &hook_tip) > BLOCK_RADIUS + HOOK_RADIUS + RANGE_TOLERANCE {
+        return Err("Hook tip does not touch block".to_string());
+    }
+
+    let thief_count = count_blocks(ctx, &thief);
+    if thief_count >= MAX_BLOCK_COUNT {
+        return Err("Grid is full".to_string());
+    }
+
+    player.last_steal = ctx.timestamp;
+    ctx.db.player().identity().update(player);
+
+    // Weighted combat against the current owner, loose blocks are free to take.
+    if block.owner != OwnerType::None {
+        let owner_count = count_blocks(ctx, &block.owner);
+        if !weighted_combat(ctx, thief_count, owner_count) {
+            return Ok(());
+        }
+    }
+
+    let previous_owner = set_block_owner(ctx, block, thief.clone())?;
+    release_disconnected_blocks(ctx, &previous_owner)?;
+    release_disconnected_blocks(ctx, &thief)?;
+
+    update_grid_load(ctx, ctx.sender);
+    if let OwnerType::Player(identity) = previous_owner {
+        update_grid_load(ctx, identity);
+    }
+    Ok(())
+}
+
+/// Rolls the weighted combat between a thief and a block owner.
+/// The more blocks the thief has compared to the owner, the more likely the steal succeeds.
+fn weighted_combat(
+    ctx: &ReducerContext,
+    thief_block_count: usize,
+    owner_block_count: usize,
+) -> bool {
+    let total_block_count = thief_block_count + owner_block_count;
+    let thief_weight = (thief_block_count + 1) as f32 / (total_block_count + 2) as f32;
+
+    ctx.rng().gen::<f32>() < thief_weight
+}
+
+/// Counts the blocks currently owned by "owner".
+fn count_blocks(ctx: &ReducerContext, owner: &OwnerType) -> usize {
+    ctx.db.block().iter().filter(|b| &b.owner == owner).count()
+}
+
+/// Every block grouped by owner, for routines that would otherwise scan the table per owner.
+fn blocks_by_owner(ctx: &ReducerContext) -> HashMap<OwnerType, Vec<Block>> {
+    let mut blocks: HashMap<OwnerType, Vec<Block>> = HashMap::new();
+    for block in ctx.db.block().iter() {
+        blocks.entry(block.owner.clone()).or_default().push(block);
+    }
+    blocks
+}
+
+/// Number of blocks "owner" has in "blocks", see "blocks_by_owner".
+fn owned_count(blocks: &HashMap<OwnerType, Vec<Block>>, owner: &OwnerType) -> usize {
+    blocks.get(owner).map_or(0, Vec::len)
+}
+
+/// Key of the "grid_cell" row for "owner" at the given grid offset.
+/// Built from the owner's fields so the stored keys do not depend on "Debug" output.
+fn grid_cell_key(owner: &OwnerType, offset_x: i32, offset_y: i32) -> String {
+    let owner = match owner {
+        OwnerType::Player(identity) => format!("player-{}", identity.to_hex()),
+        OwnerType::Bot(id) => format!("bot-{}", id),
+        OwnerType::None => "none".to_string(),
+    };
+    format!("{}:{}:{}", owner, offset_x, offset_y)
+}
+
+/// Finds the first free grid position for "owner", searching outwards from the core.
+/// Every reducer that hands out grid positions goes through this function.
+fn find_free_slot(ctx: &ReducerContext, owner: &OwnerType) -> Option<(i32, i32)> {
+    for radius in 1..=GRID_SIZE.1 {
+        for y in 0..=radius {
+            for x in -radius..=radius {
+                if x.abs() > GRID_SIZE.0 {
+                    continue;
+                }
+                // -y since blocks increment backwards
+                let pos = (x, -y);
+                if pos == (0, 0) {
+                    continue;
+                }
+                let key = grid_cell_key(owner, pos.0, pos.1);
+                if ctx.db.grid_cell().cell().find(&key).is_none() {
+                    return Some(pos);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Moves "block" to "new_owner", freeing its old grid cell and claiming a free one.
+/// Loose blocks ("OwnerType::None") do not occupy a cell. Returns the previous owner.
+fn set_block_owner(
+    ctx: &ReducerContext,
+    mut block: Block,
+    new_owner: OwnerType,
+) -> Result<OwnerType, String> {
+    let (offset_x, offset_y) = if new_owner == OwnerType::None {
+        // Loose blocks stay where their previous owner dropped them.
+        if let Some(position) = block_world_position(ctx, &block) {
+            block.position = position;
+        }
+        (block.offset_x, block.offset_y)
+    } else {
+        find_free_slot(ctx, &new_owner).ok_or("No free grid position".to_string())?
+    };
+
+    ctx.db.grid_cell().block_id().delete(block.id);
+    if new_owner != OwnerType::None {
+        ctx.db
+            .grid_cell()
+            .try_insert(GridCell {
+                cell: grid_cell_key(&new_owner, offset_x, offset_y),
+                block_id: block.id,
+            })
+            .map_err(|_| "Grid position is already taken".to_string())?;
+    }
+
+    // Attached blocks are streamed along with their owner.
+    (block.chunk_x, block.chunk_y) =
+        owner_chunk(ctx, &new_owner).unwrap_or_else(|| chunk_of(&block.position));
+    let previous_owner = std::mem::replace(&mut block.owner, new_owner);
+    block.offset_x = offset_x;
+    block.offset_y = offset_y;
+    ctx.db.block().id().update(block);
+    Ok(previous_owner)
+}
+
+/// Releases every block of "owner" that is no longer connected to the core at (0,0).
+/// Runs the same 4-neighbour BFS as the client used to, in the transaction that changed the owner.
+fn release_disconnected_blocks(ctx: &ReducerContext, owner: &OwnerType) -> Result<(), String> {
+    if *owner == OwnerType::None {
+        return Ok(());
+    }
+
+    let blocks: HashMap<(i32, i32), u64> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| &b.owner == owner)
+        .map(|b| ((b.offset_x, b.offset_y), b.id))
+        .collect();
+
+    let directions = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    // Start from the positions adjacent to the core.
+    for dir in &directions {
+        if blocks.contains_key(dir) {
+            visited.insert(*dir);
+            queue.push_back(*dir);
+        }
+    }
+
+    // BFS to find all connected blocks.
+    while let Some(current_pos) = queue.pop_front() {
+        for dir in &directions {
+            let next_pos = (current_pos.0 + dir.0, current_pos.1 + dir.1);
+            if blocks.contains_key(&next_pos) && visited.insert(next_pos) {
+                queue.push_back(next_pos);
+            }
+        }
+    }
+
+    for (pos, block_id) in blocks {
+        if visited.contains(&pos) {
+            continue;
+        }
+        if let Some(block) = ctx.db.block().id().find(block_id) {
+            set_block_owner(ctx, block, OwnerType::None)?;
+        }
+    }
+    Ok(())
+}
+
+/// Keeps the grid load and next free position of a player in line with the blocks they own.
+fn update_grid_load(ctx: &ReducerContext, identity: Identity) {
+    if let Some(mut player) = ctx.db.player().identity().find(identity) {
+        let owner = OwnerType::Player(identity);
+        player.grid.load = count_blocks(ctx, &owner) as i32;
+        if let Some((next_free_x, next_free_y)) = find_free_slot(ctx, &owner) {
+            player.grid.next_free_x = next_free_x;
+            player.grid.next_free_y = next_free_y;
+        }
+        ctx.db.player().identity().update(player);
+    }
+}
+
+/// Computes the world position of a block from its owner's transform and grid offset.
+/// Loose blocks are where they were dropped. Returns None if the owner no longer exists.
+fn block_world_position(ctx: &ReducerContext, block: &Block) -> Option<Vec2> {
+    let owner_transform = match block.owner {
+        OwnerType::Player(identity) => ctx.db.player().identity().find(identity)?.position,
+        OwnerType::Bot(bot_id) => ctx.db.bots().id().find(bot_id)?.position,
+        OwnerType::None => {
+            return Some(Vec2 {
+                x: block.position.x,
+                y: block.position.y,
+            })
+        }
+    };
+
+    Some(attached_block_position(&owner_transform, block))
+}
+
+/// World position of "block" when attached to an owner at "owner_transform".
+fn attached_block_position(owner_transform: &BevyTransform, block: &Block) -> Vec2 {
+    let local_x = block.offset_x as f32 * GRID_CELL_SIZE;
+    let local_y = block.offset_y as f32 * GRID_CELL_SIZE;
+    let (sin, cos) = owner_transform.rotation.sin_cos();
+
+    Vec2 {
+        x: owner_transform.coordinates.x + local_x * cos - local_y * sin,
+        y: owner_transform.coordinates.y + local_x * sin + local_y * cos,
+    }
+}
+
+/// Terrain speed multiplier of a player, averaged over the core and every attached block by area.
+/// The client computes the same with the same terrain, see "speed_modifer" on the client.
+fn player_speed_modifier(
+    ctx: &ReducerContext,
+    identity: Identity,
+    transform: &BevyTransform,
+) -> f32 {
+    let core = Footprint {
+        x: transform.coordinates.x,
+        y: transform.coordinates.y,
+        half_width: PLAYER_SIZE.0 / 2.0,
+        half_height: PLAYER_SIZE.1 / 2.0,
+    };
+    let owner = OwnerType::Player(identity);
+    let blocks = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.owner == owner)
+        .map(|b| attached_block_position(transform, &b))
+        .map(|pos| Footprint {
+            x: pos.x,
+            y: pos.y,
+            half_width: BLOCK_RADIUS,
+            half_height: BLOCK_RADIUS,
+        });
+
+    let footprints: Vec<Footprint> = std::iter::once(core).chain(blocks).collect();
+    terrain(ctx).speed_modifier(&footprints)
+}
+
+/// Radius of the smallest circle around the obstacle's shape.
+fn obstacle_radius(obstacle: &Obstacle) -> f32 {
+    match obstacle.shape {
+        ObstacleShape::Circle => obstacle.size.x.min(obstacle.size.y) / 2.0,
+        ObstacleShape::Box => (obstacle.size.x.powi(2) + obstacle.size.y.powi(2)).sqrt() / 2.0,
+    }
+}
+
+/// Distance from "point" to the edge of the obstacle's shape, 0 inside the obstacle.
+fn obstacle_distance(obstacle: &Obstacle, point: &Vec2) -> f32 {
+    match obstacle.shape {
+        ObstacleShape::Circle => {
+            (distance(point, &obstacle.position) - obstacle_radius(obstacle)).max(0.0)
+        }
+        ObstacleShape::Box => {
+            // Rotate the point into the box's local frame.
+            let (sin, cos) = obstacle.rotation.sin_cos();
+            let dx = point.x - obstacle.position.x;
+            let dy = point.y - obstacle.position.y;
+            let local_x = dx * cos + dy * sin;
+            let local_y = -dx * sin + dy * cos;
+
+            let outside_x = (local_x.abs() - obstacle.size.x / 2.0).max(0.0);
+            let outside_y = (local_y.abs() - obstacle.size.y / 2.0).max(0.0);
+            (outside_x.powi(2) + outside_y.powi(2)).sqrt()
+        }
+    }
+}
 
+/// Size, shape and behaviour shared by every obstacle of a kind.
+fn obstacle_kind_data(kind: ObstacleKind) -> ObstacleKindData {
+    match kind {
+        ObstacleKind::Volcano => ObstacleKindData {
+            size: Vec2 {
+                x: VOLCANO_SIZE,
+                y: VOLCANO_SIZE,
+            },
+            shape: ObstacleShape::Circle,
+            hp_factor: 1,
+            min_hook_load: 0,
+        },
+        ObstacleKind::Rock => ObstacleKindData {
+            size: Vec2 {
+                x: ROCK_SIZE.0,
+                y: ROCK_SIZE.1,
+            },
+            shape: ObstacleShape::Box,
+            hp_factor: ROCK_HP_FACTOR,
+            min_hook_load: ROCK_MIN_HOOK_LOAD,
+        },
+    }
+}
 
+/// Full HP of an obstacle of "kind".
+fn obstacle_max_hp(settings: &WorldSettings, kind: ObstacleKind) -> u32 {
+    settings
+        .obstacle_hp
+        .saturating_mul(obstacle_kind_data(kind).hp_factor)
+}
 
+fn distance(a: &Vec2, b: &Vec2) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
 
+// Function for generating bots and their blocks in server.
+// Server invokes this function in "server_startup" reducer during server intialization,
+// and in "configure_world" to add bots that are missing.
+fn generate_bots(ctx: &ReducerContext) {
+    let settings = world_settings(ctx);
 
     // Generate and insert bots into the database.
-    for (i, (x, y)) in bot_spawn_positions.into_iter().enumerate() {
-        let bot_id = i as u64; // Unique ID for each bot
+    for bot_id in 0..settings.n_bots {
+        if ctx.db.bots().id().find(bot_id).is_some() {
+            continue;
+        }
+
         let bot_transform = BevyTransform {
-            coordinates: Vec2 { x, y },
+            coordinates: bot_spawn_position(ctx, &settings, bot_id),
             rotation: 0.0, // Initial rotation
             scale: Vec2 { x: 1.0, y: 1.0 },
         };
+        let chunk = chunk_of(&bot_transform.coordinates);
 
         // Insert bot into the database.
         ctx.db.bots().insert(Bot {
@@ -511,89 +2224,167 @@ fn generate_bots(ctx: &ReducerContext) {
                 z: 0.0,
             },
             rotation_dir: 0.0,
+            state: BotState::Wander,
+            last_hook: Timestamp::UNIX_EPOCH,
+            died_at: Timestamp::UNIX_EPOCH,
+            updated_at: ctx.timestamp,
+            chunk_x: chunk.0,
+            chunk_y: chunk.1,
         });
+        give_bot_blocks(ctx, bot_id);
     }
 }
 
-/// Function for generating obstacles in server.
-/// Server invokes this function in "server_startup" reducer during server initialization.
-fn generate_blocks(ctx: &ReducerContext) {
-    let blocks_per_bot = 10;
-    let grid_size = (1, 2); // num 2 does not matter
-    let mut block_id = 0;
+/// Gives a bot "blocks_per_bot" blocks, reclaiming loose blocks first and minting new ones for the rest.
+fn give_bot_blocks(ctx: &ReducerContext, bot_id: u64) {
+    let blocks_per_bot = world_settings(ctx).blocks_per_bot as usize;
+    let owner = OwnerType::Bot(bot_id);
+    let mut loose_blocks: Vec<Block> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.owner == OwnerType::None)
+        .collect();
 
-    for bot in 0..N_BOTS {
-        let mut pos = (-1, 0);
-
-        for block_num in 0..blocks_per_bot {
-            ctx.db.block().insert(Block {
-                id: block_id,
-                offset_x: pos.0,
-                offset_y: pos.1,
-                owner: OwnerType::Bot(bot),
-            });
-            block_id += 1;
-            increment_grid_pos(&mut pos, grid_size);
+    for _ in count_blocks(ctx, &owner)..blocks_per_bot {
+        // Minted blocks start loose, then the grid allocation picks their position.
+        let block = loose_blocks
+            .pop()
+            .unwrap_or_else(|| mint_block(ctx, Vec2 { x: 0.0, y: 0.0 }));
+        let block_id = block.id;
+        if let Err(err) = set_block_owner(ctx, block, owner.clone()) {
+            log::warn!(
+                "Could not place block {} on bot {}: {}",
+                block_id,
+                bot_id,
+                err
+            );
         }
     }
 }
 
-fn increment_grid_pos(grid_pos: &mut (i32, i32), grid_max: (i32, i32)) {
-    // increment grid pos
-    grid_pos.0 += 1;
-    if *grid_pos == (0, 0) {
-        grid_pos.0 += 1;
-    }
-    if grid_pos.0 > grid_max.0 {
-        grid_pos.0 -= 1;
-        grid_pos.0 = -grid_pos.0;
-        grid_pos.1 -= 1;
+/// Inserts a new loose block at "position".
+fn mint_block(ctx: &ReducerContext, position: Vec2) -> Block {
+    let (chunk_x, chunk_y) = chunk_of(&position);
+    ctx.db.block().insert(Block {
+        // Assigned by "auto_inc".
+        id: 0,
+        offset_x: 0,
+        offset_y: 0,
+        owner: OwnerType::None,
+        position,
+        chunk_x,
+        chunk_y,
+    })
+}
+
+/// Area-of-interest chunk "position" falls in, see "AOI_CHUNK_SIZE".
+fn chunk_of(position: &Vec2) -> (i32, i32) {
+    (
+        (position.x / AOI_CHUNK_SIZE).floor() as i32,
+        (position.y / AOI_CHUNK_SIZE).floor() as i32,
+    )
+}
+
+/// Chunk of the core of "owner", None for loose blocks and unknown owners.
+fn owner_chunk(ctx: &ReducerContext, owner: &OwnerType) -> Option<(i32, i32)> {
+    match owner {
+        OwnerType::Player(identity) => ctx
+            .db
+            .player()
+            .identity()
+            .find(identity)
+            .map(|p| (p.chunk_x, p.chunk_y)),
+        OwnerType::Bot(bot_id) => ctx
+            .db
+            .bots()
+            .id()
+            .find(bot_id)
+            .map(|b| (b.chunk_x, b.chunk_y)),
+        OwnerType::None => None,
     }
+}
 
-    //grid.load += 1;
-    //player.block_count += 1;
+/// Moves every block of "owner" to the owner's new chunk.
+fn update_block_chunks(ctx: &ReducerContext, owner: &OwnerType, chunk: (i32, i32)) {
+    let blocks: Vec<Block> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| &b.owner == owner)
+        .collect();
+    for mut block in blocks {
+        (block.chunk_x, block.chunk_y) = chunk;
+        ctx.db.block().id().update(block);
+    }
 }
 
 fn generate_obstacles(ctx: &ReducerContext) {
+    let settings = world_settings(ctx);
     // Initialize 2 noise generators with different seeds.
-    let perlin_x = Perlin::new(21);
-    let perlin_y = Perlin::new(1345);
-    // Generate 200 obstacles.
-    for i in 0..150 {
+    let perlin_x = Perlin::new(settings.obstacle_seed_x);
+    let perlin_y = Perlin::new(settings.obstacle_seed_y);
+    let terrain = terrain(ctx);
+    let (half_width, half_height) = terrain.half_size();
+    // Generate the configured number of obstacles.
+    for i in 0..settings.n_obstacles {
         // Control frequency.
         let x = (i as f32) / 10.0;
         let y = ((i + 1) as f32) / 10.0;
 
-        // Noise generate x & y values within the map (8192*8192).
-        let random_x = perlin_x.get([x as f64, y as f64]) as f32 * 16834.0;
-        let random_y = perlin_y.get([y as f64, x as f64]) as f32 * 16834.0;
+        // Noise generate x & y values within the map.
+        let random_x = perlin_x.get([x as f64, y as f64]) as f32 * half_width;
+        let random_y = perlin_y.get([y as f64, x as f64]) as f32 * half_height;
 
         // Define invalid x & y values within the safe zone (spawn point).
-        let invalid_x = random_x < 300.0 && random_x > -300.0;
-        let invalid_y = random_y < 300.0 && random_y > -300.0;
+        let invalid_x = random_x < SAFE_ZONE_SIZE && random_x > -SAFE_ZONE_SIZE;
+        let invalid_y = random_y < SAFE_ZONE_SIZE && random_y > -SAFE_ZONE_SIZE;
 
         // If noise generated spawnpoint is within safe zone, do not spawn obstacle.
         if invalid_x && invalid_y {
             continue;
         }
+        // Obstacles are not placed in lava.
+        if terrain.tile_at(random_x, random_y) == Some(TileKind::Lava) {
+            continue;
+        }
+
+        // Noise generate the rotation and kind as well, so every client sees the same layout.
+        let rotation = perlin_x.get([y as f64, x as f64]) as f32 * std::f32::consts::PI;
+        let kind = if perlin_y.get([x as f64, x as f64]) > 0.0 {
+            ObstacleKind::Volcano
+        } else {
+            ObstacleKind::Rock
+        };
+        let kind_data = obstacle_kind_data(kind);
+
+        let position = Vec2 {
+            // Insert noise generated x & y values.
+            x: random_x,
+            y: random_y,
+        };
+        let (chunk_x, chunk_y) = chunk_of(&position);
 
         // Insert column in "obstacle" table.
         ctx.db.obstacle().insert(Obstacle {
-            position: Vec2 {
-                // Insert noise generated x & y values.
-                x: random_x,
-                y: random_y,
-            },
+            position,
+            size: kind_data.size,
+            rotation,
+            kind,
+            shape: kind_data.shape,
             // Set ID to iteration integer "i".
             id: i,
-            // Set default HP value.
-            hp: 100,
+            // Set configured HP value, scaled for the kind.
+            hp: obstacle_max_hp(&settings, kind),
+            respawn_at: None,
+            chunk_x,
+            chunk_y,
         });
     }
 }
 
-#[spacetimedb::reducer]
-pub fn generate_leaderboard(ctx: &ReducerContext) {
+/// Inserts the single leaderboard row, called once in "server_startup".
+fn generate_leaderboard(ctx: &ReducerContext) {
     // Create a new leaderboard entry with an empty top players list
     let leaderboard = Leaderboard {
         id: 1, // id är 1
@@ -0,0 +1,61 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::MapSource;
+pub use rustbourn_terrain::{Footprint, Terrain, TileKind};
+
+// Largest procedural map, in tiles along either side.
+const MAX_NOISE_MAP_SIZE: u32 = 1024;
+
+/// Map hosted when the module is first published, admins can change it through "configure_world".
+pub fn default_map_source() -> MapSource {
+    MapSource::Image("map-flipped".to_string())
+}
+
+/// Bytes of a map image in the client's assets, the server embeds the ones it can host.
+fn map_image(name: &str) -> Option<&'static [u8]> {
+    match name {
+        "map-flipped" => Some(include_bytes!("../../client/assets/map-flipped.png")),
+        _ => None,
+    }
+}
+
+/// Checks that the server can build the terrain of "source".
+pub fn validate_map_source(source: &MapSource) -> Result<(), String> {
+    match source {
+        MapSource::Image(name) if map_image(name).is_none() => Err(format!(
+            "Map image \"{}\" is not embedded in the server",
+            name
+        )),
+        MapSource::Noise(map)
+            if !(1..=MAX_NOISE_MAP_SIZE).contains(&map.width)
+                || !(1..=MAX_NOISE_MAP_SIZE).contains(&map.height) =>
+        {
+            Err(format!(
+                "Map width and height must be between 1 and {}",
+                MAX_NOISE_MAP_SIZE
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the terrain of "source", decoding or generating the map when the source changes.
+pub fn terrain(source: &MapSource) -> Rc<Terrain> {
+    thread_local! {
+        static TERRAIN: RefCell<Option<(MapSource, Rc<Terrain>)>> = const { RefCell::new(None) };
+    }
+    TERRAIN.with_borrow_mut(|cached| match cached {
+        Some((cached_source, terrain)) if cached_source == source => terrain.clone(),
+        _ => {
+            let terrain = Rc::new(match source {
+                MapSource::Image(name) => Terrain::decode(
+                    map_image(name).expect("Map image is not embedded in the server"),
+                ),
+                MapSource::Noise(map) => Terrain::generate(map.seed, map.width, map.height),
+            });
+            *cached = Some((source.clone(), terrain.clone()));
+            terrain
+        }
+    })
+}
@@ -0,0 +1,431 @@
+//! Terrain model shared by the server module and the client tilemap.
+//! Both sides classify the pixels of the same map image with the same colour rules,
+//! or generate the same procedural map from the same seed.
+
+use noise::{NoiseFn, Simplex};
+
+// Tile size in world units, the client's MAP_CONFIG uses the same. The map size comes from the image.
+pub const TILE_SIZE: f32 = 32.0;
+
+// Procedural maps, how many noise units one tile spans.
+const NOISE_SCALE: f64 = 0.02;
+// Tiles around the map center kept as grass, so players never spawn in lava or water.
+const SPAWN_CLEARING: i64 = 12;
+
+/// Kind of terrain a tile is made of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TileKind {
+    Regular,
+    Water,
+    Stone,
+    Lava,
+}
+
+impl TileKind {
+    /// Movement speed multiplier on this kind of tile.
+    pub fn speed_modifier(self) -> f32 {
+        match self {
+            TileKind::Regular => 1.0,
+            TileKind::Water => 0.2,
+            TileKind::Stone => 0.7,
+            TileKind::Lava => 0.5,
+        }
+    }
+}
+
+/// Axis-aligned rectangle covered by a core or a block, in world units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Footprint {
+    pub x: f32,
+    pub y: f32,
+    pub half_width: f32,
+    pub half_height: f32,
+}
+
+/// Surface drawn on a tile, the finer classification the client picks textures from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Surface {
+    Grass,
+    Water,
+    Stone,
+    Dirt,
+    Lava,
+    WaterGrass,
+    WaterStone,
+    DirtGrass,
+    DirtStone,
+    StoneGrass,
+    // Pixel matching no colour rule, treated as lava
+    Unknown,
+}
+
+impl Surface {
+    /// Terrain kind of the surface, what gameplay checks care about.
+    pub fn kind(self) -> TileKind {
+        match self {
+            Surface::Grass
+            | Surface::Dirt
+            | Surface::DirtGrass
+            | Surface::DirtStone
+            | Surface::StoneGrass => TileKind::Regular,
+            Surface::Water | Surface::WaterGrass | Surface::WaterStone => TileKind::Water,
+            Surface::Stone => TileKind::Stone,
+            Surface::Lava | Surface::Unknown => TileKind::Lava,
+        }
+    }
+}
+
+/// Terrain model, one surface per map pixel.
+pub struct Terrain {
+    width: u32,
+    height: u32,
+    surfaces: Vec<Surface>,
+}
+
+impl Terrain {
+    /// Decodes a map image, each pixel becomes one tile.
+    pub fn decode(bytes: &[u8]) -> Terrain {
+        let mut decoder = png::Decoder::new(bytes);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info().expect("Failed to read map image");
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader
+            .next_frame(&mut buf)
+            .expect("Failed to decode map image");
+
+        let samples = info.color_type.samples();
+        let surfaces = buf[..info.buffer_size()]
+            .chunks_exact(samples)
+            .map(|pixel| match samples {
+                // Grayscale images only have one channel
+                1 | 2 => classify_pixel(pixel[0], pixel[0], pixel[0]),
+                _ => classify_pixel(pixel[0], pixel[1], pixel[2]),
+            })
+            .collect();
+
+        Terrain {
+            width: info.width,
+            height: info.height,
+            surfaces,
+        }
+    }
+
+    /// Generates a map of "width" by "height" tiles from two layers of simplex noise.
+    /// The same seed always gives the same map.
+    pub fn generate(seed: u32, width: u32, height: u32) -> Terrain {
+        // Elevation decides water, land, stone and lava, the second layer spreads dirt over the land.
+        let elevation = Simplex::new(seed);
+        let soil = Simplex::new(seed.wrapping_add(1));
+        let (center_x, center_y) = (width as i64 / 2, height as i64 / 2);
+
+        let mut surfaces = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let clearing = (x as i64 - center_x).abs() <= SPAWN_CLEARING
+                    && (y as i64 - center_y).abs() <= SPAWN_CLEARING;
+                let point = [x as f64 * NOISE_SCALE, y as f64 * NOISE_SCALE];
+                let e = elevation.get(point);
+                let d = soil.get(point);
+
+                let surface = match e {
+                    _ if clearing => Surface::Grass,
+                    e if e < -0.45 => Surface::Water,
+                    e if e < -0.35 => Surface::WaterGrass,
+                    e if e > 0.65 => Surface::Lava,
+                    e if e > 0.5 => Surface::Stone,
+                    e if e > 0.4 => Surface::StoneGrass,
+                    _ if d > 0.3 => Surface::Dirt,
+                    _ if d > 0.2 => Surface::DirtGrass,
+                    _ => Surface::Grass,
+                };
+                surfaces.push(surface);
+            }
+        }
+
+        Terrain {
+            width,
+            height,
+            surfaces,
+        }
+    }
+
+    /// Width and height of the map in tiles.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Surface at tile coordinates, None outside the map.
+    pub fn surface(&self, x: i32, y: i32) -> Option<Surface> {
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return None;
+        }
+        Some(self.surfaces[(y as u32 * self.width + x as u32) as usize])
+    }
+
+    /// Tile kind at tile coordinates, None outside the map.
+    pub fn tile(&self, x: i32, y: i32) -> Option<TileKind> {
+        self.surface(x, y).map(Surface::kind)
+    }
+
+    /// Half the width and height of the map in world units, the map is centered on the origin.
+    pub fn half_size(&self) -> (f32, f32) {
+        (
+            self.width as f32 * TILE_SIZE / 2.0,
+            self.height as f32 * TILE_SIZE / 2.0,
+        )
+    }
+
+    /// Converts world coordinates to tile coordinates.
+    pub fn world_to_tile(&self, x: f32, y: f32) -> (i32, i32) {
+        let (half_width, half_height) = self.half_size();
+        (
+            ((x + half_width) / TILE_SIZE).floor() as i32,
+            ((y + half_height) / TILE_SIZE).floor() as i32,
+        )
+    }
+
+    /// Tile kind at world coordinates, None outside the map.
+    pub fn tile_at(&self, x: f32, y: f32) -> Option<TileKind> {
+        let (tile_x, tile_y) = self.world_to_tile(x, y);
+        self.tile(tile_x, tile_y)
+    }
+
+    /// Movement speed multiplier of a body made of "footprints". Each footprint averages the tiles
+    /// it overlaps, and the footprints are weighted by their area. Tiles outside the map count as regular.
+    pub fn speed_modifier(&self, footprints: &[Footprint]) -> f32 {
+        let mut weighted_sum = 0.0;
+        let mut total_area = 0.0;
+
+        for footprint in footprints {
+            let (x_start, y_start) = self.world_to_tile(
+                footprint.x - footprint.half_width,
+                footprint.y - footprint.half_height,
+            );
+            let (x_end, y_end) = self.world_to_tile(
+                footprint.x + footprint.half_width,
+                footprint.y + footprint.half_height,
+            );
+
+            let mut sum = 0.0;
+            let mut count = 0;
+            for tile_x in x_start..=x_end {
+                for tile_y in y_start..=y_end {
+                    sum += self
+                        .tile(tile_x, tile_y)
+                        .map_or(1.0, TileKind::speed_modifier);
+                    count += 1;
+                }
+            }
+
+            let area = 4.0 * footprint.half_width * footprint.half_height;
+            weighted_sum += sum / count as f32 * area;
+            total_area += area;
+        }
+
+        if total_area > 0.0 {
+            weighted_sum / total_area
+        } else {
+            1.0
+        }
+    }
+
+    /// Checks if any tile overlapped by the box centered at ("x", "y") is of "kind" or outside the map.
+    pub fn box_touches(&self, x: f32, y: f32, half_size: f32, kind: TileKind) -> bool {
+        let (x_start, y_start) = self.world_to_tile(x - half_size, y - half_size);
+        let (x_end, y_end) = self.world_to_tile(x + half_size, y + half_size);
+
+        for tile_x in x_start..=x_end {
+            for tile_y in y_start..=y_end {
+                match self.tile(tile_x, tile_y) {
+                    Some(tile) if tile != kind => {}
+                    _ => return true,
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Maps a pixel colour of the map image to a surface.
+/// The client picks a texture from the surface, the server only needs its "kind".
+pub fn classify_pixel(r: u8, g: u8, b: u8) -> Surface {
+    if r <= 20 && g >= 230 && b <= 20 {
+        Surface::Grass
+    } else if r <= 20 && g <= 20 && b >= 230 {
+        Surface::Water
+    } else if r <= 20 && g <= 20 && b <= 20 {
+        Surface::Stone
+    } else if r >= 230 && g >= 230 && b >= 230 {
+        Surface::Dirt
+    } else if r >= 230 && g <= 20 && b <= 20 {
+        Surface::Lava
+    } else if r <= 20 && g >= 230 && b >= 230 {
+        Surface::WaterGrass
+    } else if r >= 230 && g <= 20 && b >= 230 {
+        Surface::WaterStone
+    } else if r >= 230 && g >= 230 && b <= 20 {
+        Surface::DirtGrass
+    } else if (100..=200).contains(&r) && (100..=200).contains(&g) && (100..=200).contains(&b) {
+        Surface::DirtStone
+    } else if (30..=70).contains(&r) && (30..=70).contains(&g) && (30..=70).contains(&b) {
+        Surface::StoneGrass
+    } else {
+        Surface::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds a map from rows of surfaces, the first row is tile row 0.
+    fn terrain_from(rows: &[&[Surface]]) -> Terrain {
+        Terrain {
+            width: rows[0].len() as u32,
+            height: rows.len() as u32,
+            surfaces: rows.iter().flat_map(|row| row.iter().copied()).collect(),
+        }
+    }
+
+    #[test]
+    fn classify_pixel_matches_colour_rules() {
+        assert_eq!(classify_pixel(0, 255, 0), Surface::Grass);
+        assert_eq!(classify_pixel(0, 0, 255), Surface::Water);
+        assert_eq!(classify_pixel(0, 0, 0), Surface::Stone);
+        assert_eq!(classify_pixel(255, 255, 255), Surface::Dirt);
+        assert_eq!(classify_pixel(255, 0, 0), Surface::Lava);
+        assert_eq!(classify_pixel(0, 255, 255), Surface::WaterGrass);
+        assert_eq!(classify_pixel(255, 0, 255), Surface::WaterStone);
+        assert_eq!(classify_pixel(255, 255, 0), Surface::DirtGrass);
+        assert_eq!(classify_pixel(150, 150, 150), Surface::DirtStone);
+        assert_eq!(classify_pixel(50, 50, 50), Surface::StoneGrass);
+    }
+
+    #[test]
+    fn classify_pixel_tolerates_slight_colour_drift() {
+        assert_eq!(classify_pixel(20, 230, 20), Surface::Grass);
+        assert_eq!(classify_pixel(20, 20, 20), Surface::Stone);
+        assert_eq!(classify_pixel(230, 230, 230), Surface::Dirt);
+    }
+
+    #[test]
+    fn classify_pixel_unknown_colour_is_lava() {
+        let surface = classify_pixel(90, 10, 210);
+        assert_eq!(surface, Surface::Unknown);
+        assert_eq!(surface.kind(), TileKind::Lava);
+    }
+
+    #[test]
+    fn generate_is_deterministic_for_a_seed() {
+        let first = Terrain::generate(42, 64, 48);
+        let second = Terrain::generate(42, 64, 48);
+        assert_eq!(first.dimensions(), (64, 48));
+        assert_eq!(first.surfaces, second.surfaces);
+    }
+
+    #[test]
+    fn generate_keeps_spawn_clearing_as_grass() {
+        let terrain = Terrain::generate(7, 64, 64);
+        for y in 32 - SPAWN_CLEARING as i32..=32 + SPAWN_CLEARING as i32 {
+            for x in 32 - SPAWN_CLEARING as i32..=32 + SPAWN_CLEARING as i32 {
+                assert_eq!(terrain.surface(x, y), Some(Surface::Grass));
+            }
+        }
+    }
+
+    #[test]
+    fn tile_at_is_none_outside_the_map() {
+        use Surface::*;
+        // 2 by 2 tiles, the map spans -32..32 on both axes
+        let terrain = terrain_from(&[&[Grass, Water], &[Stone, Lava]]);
+
+        assert_eq!(terrain.tile_at(-32.0, -32.0), Some(TileKind::Regular));
+        assert_eq!(terrain.tile_at(0.0, -1.0), Some(TileKind::Water));
+        assert_eq!(terrain.tile_at(-1.0, 0.0), Some(TileKind::Stone));
+        assert_eq!(terrain.tile_at(31.9, 31.9), Some(TileKind::Lava));
+
+        assert_eq!(terrain.tile_at(32.0, 0.0), None);
+        assert_eq!(terrain.tile_at(0.0, 32.0), None);
+        assert_eq!(terrain.tile_at(-32.1, 0.0), None);
+        assert_eq!(terrain.tile_at(0.0, -32.1), None);
+    }
+
+    #[test]
+    fn box_touches_finds_overlapped_kind() {
+        use Surface::*;
+        // 3 by 3 tiles with water in the top-right corner, the map spans -48..48
+        let terrain = terrain_from(&[
+            &[Grass, Grass, Water],
+            &[Grass, Grass, Grass],
+            &[Grass, Grass, Grass],
+        ]);
+
+        // Inside the center tile only
+        assert!(!terrain.box_touches(0.0, 0.0, 10.0, TileKind::Water));
+        // Reaching into the water tile
+        assert!(terrain.box_touches(14.0, -14.0, 10.0, TileKind::Water));
+        // Fully inside the water tile
+        assert!(terrain.box_touches(32.0, -32.0, 4.0, TileKind::Water));
+        // Far from the water tile
+        assert!(!terrain.box_touches(-32.0, 32.0, 10.0, TileKind::Water));
+    }
+
+    #[test]
+    fn box_touches_counts_outside_the_map() {
+        let terrain = terrain_from(&[&[Surface::Grass]]);
+
+        assert!(!terrain.box_touches(0.0, 0.0, 10.0, TileKind::Lava));
+        assert!(terrain.box_touches(10.0, 0.0, 10.0, TileKind::Lava));
+    }
+
+    fn footprint(x: f32, y: f32, half_size: f32) -> Footprint {
+        Footprint {
+            x,
+            y,
+            half_width: half_size,
+            half_height: half_size,
+        }
+    }
+
+    #[test]
+    fn speed_modifier_core_alone() {
+        use Surface::*;
+        // 2 by 2 tiles, the map spans -32..32 on both axes
+        let terrain = terrain_from(&[&[Grass, Water], &[Stone, Lava]]);
+
+        let on_grass = terrain.speed_modifier(&[footprint(-16.0, -16.0, 10.0)]);
+        assert_eq!(on_grass, 1.0);
+        let on_water = terrain.speed_modifier(&[footprint(16.0, -16.0, 10.0)]);
+        assert_eq!(on_water, 0.2);
+        // Straddling grass and stone averages the two
+        let straddling = terrain.speed_modifier(&[footprint(-16.0, 0.0, 10.0)]);
+        assert!((straddling - 0.85).abs() < 1e-6);
+    }
+
+    #[test]
+    fn speed_modifier_weights_blocks_by_area() {
+        use Surface::*;
+        let terrain = terrain_from(&[&[Grass, Water], &[Stone, Lava]]);
+
+        // Core on grass, a block of the same size on water
+        let even =
+            terrain.speed_modifier(&[footprint(-16.0, -16.0, 10.0), footprint(16.0, -16.0, 10.0)]);
+        assert!((even - 0.6).abs() < 1e-6);
+
+        // Core on grass, two blocks of a quarter its area on stone and lava
+        let mixed = terrain.speed_modifier(&[
+            footprint(-16.0, -16.0, 10.0),
+            footprint(-16.0, 16.0, 5.0),
+            footprint(16.0, 16.0, 5.0),
+        ]);
+        let expected = (1.0 * 4.0 + 0.7 + 0.5) / 6.0;
+        assert!((mixed - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn speed_modifier_without_footprints_is_neutral() {
+        let terrain = terrain_from(&[&[Surface::Water]]);
+
+        assert_eq!(terrain.speed_modifier(&[]), 1.0);
+    }
+}